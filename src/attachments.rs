@@ -1,9 +1,12 @@
 //! Interfaces for accessing and managing attachments
 
+// Third party
+use reqwest::blocking::multipart::{Form, Part};
 use std::collections::BTreeMap;
+use std::io::Read;
 
 // Ours
-use crate::{Jira, Result};
+use crate::{Attachment, Jira, Result};
 
 /// Same as `User`, but without `email_address`
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -71,4 +74,61 @@ impl Attachments {
 
         Ok(())
     }
+
+    /// Upload a file as attachment to an issue
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/8.13.8/#api/2/issue/{issueIdOrKey}/attachments-addAttachment)
+    /// for more information
+    pub fn upload<K, F>(&self, issue_key: K, filename: F, data: Vec<u8>) -> Result<Vec<Attachment>>
+    where
+        K: Into<String>,
+        F: Into<String>,
+    {
+        let form = Form::new().part("file", Part::bytes(data).file_name(filename.into()));
+
+        self.jira.post_multipart(
+            "api",
+            &format!("/issue/{}/attachments", issue_key.into()),
+            form,
+        )
+    }
+
+    /// Upload an attachment to an issue, streaming its content from a reader
+    ///
+    /// Unlike [`Attachments::upload`] the content isn't buffered in memory,
+    /// which suits large files. The body is sent chunked as its length is
+    /// unknown.
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/8.13.8/#api/2/issue/{issueIdOrKey}/attachments-addAttachment)
+    /// for more information
+    pub fn upload_stream<K, F, R>(
+        &self,
+        issue_key: K,
+        filename: F,
+        reader: R,
+    ) -> Result<Vec<Attachment>>
+    where
+        K: Into<String>,
+        F: Into<String>,
+        R: Read + Send + 'static,
+    {
+        let form = Form::new().part("file", Part::reader(reader).file_name(filename.into()));
+
+        self.jira.post_multipart(
+            "api",
+            &format!("/issue/{}/attachments", issue_key.into()),
+            form,
+        )
+    }
+
+    /// Download the content of an attachment, given its `content` url
+    ///
+    /// The url must point to the Jira host, others are refused with
+    /// `Error::InvalidInput` so the credentials don't leak to other hosts.
+    pub fn download<U>(&self, content_url: U) -> Result<Vec<u8>>
+    where
+        U: AsRef<str>,
+    {
+        self.jira.download(content_url.as_ref())
+    }
 }
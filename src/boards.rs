@@ -4,7 +4,7 @@
 use url::form_urlencoded;
 
 // Ours
-use crate::{Jira, Result, SearchOptions};
+use crate::{EmptyResponse, Jira, PageFetcher, PageIter, Paginated, Result, SearchOptions};
 
 #[derive(Debug)]
 pub struct Boards {
@@ -41,6 +41,18 @@ pub struct Location {
     pub name: Option<String>,
 }
 
+/// Request body for `Boards::create`
+#[derive(Serialize, Debug, Clone)]
+pub struct CreateBoard {
+    pub name: String,
+    /// Either `scrum` or `kanban`
+    #[serde(rename = "type")]
+    pub type_name: String,
+    /// Id of the saved filter selecting the issues of the board
+    #[serde(rename = "filterId")]
+    pub filter_id: u64,
+}
+
 #[derive(Deserialize, Debug)]
 pub struct BoardResults {
     #[serde(rename = "maxResults")]
@@ -68,6 +80,24 @@ impl Boards {
         self.jira.get("agile", &format!("/board/{}", id.into()))
     }
 
+    /// Create a new board
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/board-createBoard)
+    /// for more information
+    pub fn create(&self, data: CreateBoard) -> Result<Board> {
+        self.jira.post("agile", "/board", data)
+    }
+
+    /// Delete a board
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/board-deleteBoard)
+    /// for more information
+    pub fn delete(&self, id: u64) -> Result<()> {
+        self.jira
+            .delete::<EmptyResponse>("agile", &format!("/board/{id}"))
+            .map(|_| ())
+    }
+
     /// Returns a single page of board results
     ///
     /// See the [jira docs](https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/board-getAllBoards)
@@ -95,48 +125,75 @@ impl Boards {
 /// Provides an iterator over multiple pages of search results
 #[derive(Debug)]
 pub struct BoardsIter<'a> {
-    jira: Jira,
-    results: BoardResults,
-    search_options: &'a SearchOptions,
+    pages: PageIter<BoardPages<'a>>,
 }
 
 impl<'a> BoardsIter<'a> {
     fn new(options: &'a SearchOptions, jira: &Jira) -> Result<Self> {
-        let results = jira.boards().list(options)?;
-        Ok(BoardsIter {
+        let fetcher = BoardPages {
             jira: jira.clone(),
-            results,
             search_options: options,
+        };
+        let results = fetcher.fetch_first()?;
+        Ok(BoardsIter {
+            pages: PageIter::new(results, fetcher),
         })
     }
-
-    fn more(&self) -> bool {
-        !self.results.is_last
-    }
 }
 
 impl<'a> Iterator for BoardsIter<'a> {
     type Item = Board;
     fn next(&mut self) -> Option<Board> {
-        self.results.values.pop().or_else(|| {
-            if self.more() {
-                match self.jira.boards().list(
-                    &self
-                        .search_options
-                        .as_builder()
-                        .max_results(self.results.max_results)
-                        .start_at(self.results.start_at + self.results.max_results)
-                        .build(),
-                ) {
-                    Ok(new_results) => {
-                        self.results = new_results;
-                        self.results.values.pop()
-                    }
-                    _ => None,
-                }
-            } else {
-                None
-            }
-        })
+        self.pages.next()
+    }
+}
+
+#[derive(Debug)]
+struct BoardPages<'a> {
+    jira: Jira,
+    search_options: &'a SearchOptions,
+}
+
+impl<'a> BoardPages<'a> {
+    fn fetch_first(&self) -> Result<BoardResults> {
+        self.jira.boards().list(self.search_options)
+    }
+}
+
+impl<'a> PageFetcher for BoardPages<'a> {
+    type Page = BoardResults;
+    fn fetch(&self, start_at: u64, max_results: u64) -> Result<BoardResults> {
+        self.jira.boards().list(
+            &self
+                .search_options
+                .as_builder()
+                .max_results(max_results)
+                .start_at(start_at)
+                .build(),
+        )
+    }
+}
+
+impl Paginated for BoardResults {
+    type Item = Board;
+
+    fn start_at(&self) -> u64 {
+        self.start_at
+    }
+
+    fn max_results(&self) -> u64 {
+        self.max_results
+    }
+
+    fn is_last(&self) -> Option<bool> {
+        Some(self.is_last)
+    }
+
+    fn items(&self) -> &[Board] {
+        &self.values
+    }
+
+    fn into_items(self) -> Vec<Board> {
+        self.values
     }
 }
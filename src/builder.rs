@@ -1,11 +1,17 @@
 // Third party
-use std::collections::HashMap;
+use reqwest::blocking::Client;
+use std::collections::BTreeMap;
+use std::time::Duration;
 use url::form_urlencoded;
 
+// Ours
+use crate::middleware::Middlewares;
+use crate::{Credentials, Jira, RequestMiddleware, Result};
+
 /// Options availble for search
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
 pub struct SearchOptions {
-    params: HashMap<&'static str, String>,
+    params: BTreeMap<&'static str, String>,
 }
 
 impl SearchOptions {
@@ -15,6 +21,9 @@ impl SearchOptions {
     }
 
     /// Serialize options as a string. returns None if no options are defined
+    ///
+    /// Values are form url encoded and parameters are emitted in a stable,
+    /// sorted order, so equal options always serialize the same way.
     pub fn serialize(&self) -> Option<String> {
         if self.params.is_empty() {
             None
@@ -27,16 +36,87 @@ impl SearchOptions {
         }
     }
 
+    /// Returns a builder starting from these options, e.g. to change the
+    /// page offset. Building it without changes yields equal options.
     pub fn as_builder(&self) -> SearchOptionsBuilder {
         SearchOptionsBuilder::copy_from(self)
     }
+
+    /// Returns these options starting at another offset
+    pub fn with_start_at(mut self, start_at: u64) -> SearchOptions {
+        self.params.insert("startAt", start_at.to_string());
+        self
+    }
+
+    /// Returns these options with another page size
+    pub fn with_max_results(mut self, max_results: u64) -> SearchOptions {
+        self.params.insert("maxResults", max_results.to_string());
+        self
+    }
+
+    /// Returns these options filtering with another `jql` parameter
+    ///
+    /// Meant for the issue listings of boards and sprints. [`Search`](crate::Search)
+    /// takes the query as its own argument and ignores a `jql` parameter.
+    pub fn with_jql<J>(mut self, jql: J) -> SearchOptions
+    where
+        J: Into<String>,
+    {
+        self.params.insert("jql", jql.into());
+        self
+    }
+
+    /// Returns these options, restricting the fields to the given ones unless
+    /// fields were already chosen
+    pub(crate) fn or_fields(&self, fields: &[String]) -> SearchOptions {
+        let mut options = self.clone();
+        options
+            .params
+            .entry("fields")
+            .or_insert_with(|| fields.join(","));
+        options
+    }
+
+    /// Field tokens requested with `fields`, if any
+    pub(crate) fn fields(&self) -> Option<Vec<String>> {
+        self.params.get("fields").map(|fields| {
+            fields
+                .split(',')
+                .map(str::trim)
+                .filter(|field| !field.is_empty())
+                .map(str::to_owned)
+                .collect()
+        })
+    }
+
+    /// Returns these options without a `jql` parameter
+    pub(crate) fn without_jql(mut self) -> SearchOptions {
+        self.params.remove("jql");
+        self
+    }
+
+    /// Field tokens of a single issue, which defaults to `*all`
+    pub(crate) fn fetched_fields(&self) -> Vec<String> {
+        self.fields().unwrap_or_else(|| vec!["*all".to_owned()])
+    }
+
+    /// Field tokens of an issue listing, which defaults to `*navigable`
+    pub(crate) fn listed_fields(&self) -> Vec<String> {
+        self.fields()
+            .unwrap_or_else(|| vec!["*navigable".to_owned()])
+    }
+
+    /// Page size requested with `max_results`, if any
+    pub(crate) fn max_results(&self) -> Option<u64> {
+        self.params.get("maxResults")?.parse().ok()
+    }
 }
 
 /// A builder interface for search option. Typically this
 /// is initialized with SearchOptions::builder()
 #[derive(Default, Debug)]
 pub struct SearchOptionsBuilder {
-    params: HashMap<&'static str, String>,
+    params: BTreeMap<&'static str, String>,
 }
 
 impl SearchOptionsBuilder {
@@ -52,6 +132,10 @@ impl SearchOptionsBuilder {
         }
     }
 
+    /// Restricts the fields returned
+    ///
+    /// Values are passed on unmodified, so Jira's special tokens like `*all`,
+    /// `*navigable` and exclusions like `-comment` can be used as well.
     pub fn fields<F>(&mut self, fs: Vec<F>) -> &mut SearchOptionsBuilder
     where
         F: Into<String>,
@@ -66,6 +150,32 @@ impl SearchOptionsBuilder {
         self
     }
 
+    /// Requests entity properties by key, e.g. `properties=myapp.meta`
+    pub fn properties<P>(&mut self, ps: Vec<P>) -> &mut SearchOptionsBuilder
+    where
+        P: Into<String>,
+    {
+        self.params.insert(
+            "properties",
+            ps.into_iter()
+                .map(|p| p.into())
+                .collect::<Vec<String>>()
+                .join(","),
+        );
+        self
+    }
+
+    /// Returns all fields except the given ones, i.e. `fields=*all,-comment`
+    pub fn exclude_fields<F>(&mut self, fs: Vec<F>) -> &mut SearchOptionsBuilder
+    where
+        F: Into<String>,
+    {
+        let fields = std::iter::once("*all".to_owned())
+            .chain(fs.into_iter().map(|f| format!("-{}", f.into())))
+            .collect::<Vec<_>>();
+        self.fields(fields)
+    }
+
     pub fn validate(&mut self, v: bool) -> &mut SearchOptionsBuilder {
         self.params.insert("validateQuery", v.to_string());
         self
@@ -110,11 +220,19 @@ impl SearchOptionsBuilder {
         self
     }
 
+    /// Free text filter, e.g. matching the key or name in a project search
+    pub fn query(&mut self, q: &str) -> &mut SearchOptionsBuilder {
+        self.params.insert("query", q.to_string());
+        self
+    }
+
     pub fn state(&mut self, s: &str) -> &mut SearchOptionsBuilder {
         self.params.insert("state", s.to_string());
         self
     }
 
+    /// Filters the results of endpoints accepting a `jql` parameter, e.g. only
+    /// unresolved issues with `Issues::list`
     pub fn jql(&mut self, s: &str) -> &mut SearchOptionsBuilder {
         self.params.insert("jql", s.to_string());
         self
@@ -131,3 +249,172 @@ impl SearchOptionsBuilder {
         }
     }
 }
+
+/// A builder interface for a configured [`Jira`] client. Typically this
+/// is initialized with `Jira::builder()`
+#[derive(Debug)]
+pub struct JiraBuilder {
+    host: String,
+    credentials: Credentials,
+    timeout: Option<Duration>,
+    user_agent: Option<String>,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    base_path: Option<String>,
+    api_paths: Vec<(String, String)>,
+    default_issue_fields: Option<Vec<String>>,
+    middlewares: Middlewares,
+    read_only: bool,
+    http_client: Option<Client>,
+}
+
+impl JiraBuilder {
+    /// Creates a new instance for the given host, without credentials
+    pub fn new<H>(host: H) -> JiraBuilder
+    where
+        H: Into<String>,
+    {
+        JiraBuilder {
+            host: host.into(),
+            credentials: Credentials::Anonymous,
+            timeout: None,
+            user_agent: None,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            base_path: None,
+            api_paths: vec![],
+            default_issue_fields: None,
+            middlewares: Middlewares::default(),
+            read_only: false,
+            http_client: None,
+        }
+    }
+
+    pub fn credentials(&mut self, credentials: Credentials) -> &mut JiraBuilder {
+        self.credentials = credentials;
+        self
+    }
+
+    /// Uses a preconfigured HTTP client, e.g. to share its connection pool or
+    /// TLS and proxy settings
+    ///
+    /// The client is used as-is, so `timeout`, `user_agent` and the pool
+    /// settings have no effect and have to be configured on it instead.
+    pub fn http_client(&mut self, client: Client) -> &mut JiraBuilder {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Timeout of each request, from connecting until the body is read
+    pub fn timeout(&mut self, timeout: Duration) -> &mut JiraBuilder {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn user_agent<U>(&mut self, user_agent: U) -> &mut JiraBuilder
+    where
+        U: Into<String>,
+    {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Maximum number of idle connections kept open to the host
+    ///
+    /// By default reqwest doesn't limit them. When copies of the client are
+    /// used from many threads, e.g. for bulk operations, roughly one idle
+    /// connection per thread avoids reconnecting between requests without
+    /// holding on to more connections than needed.
+    pub fn pool_max_idle_per_host(&mut self, max: usize) -> &mut JiraBuilder {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// How long an idle connection is kept open, 90 seconds by default
+    ///
+    /// Lowering it releases connections of bursty bulk operations sooner,
+    /// while batches paused for longer than it reconnect for every burst.
+    pub fn pool_idle_timeout(&mut self, timeout: Duration) -> &mut JiraBuilder {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// See [`Jira::with_base_path`]
+    pub fn base_path<P>(&mut self, base_path: P) -> &mut JiraBuilder
+    where
+        P: Into<String>,
+    {
+        self.base_path = Some(base_path.into());
+        self
+    }
+
+    /// See [`Jira::with_base_api_path`]
+    pub fn base_api_path<A, P>(&mut self, api_name: A, path: P) -> &mut JiraBuilder
+    where
+        A: Into<String>,
+        P: Into<String>,
+    {
+        self.api_paths.push((api_name.into(), path.into()));
+        self
+    }
+
+    /// See [`Jira::with_default_issue_fields`]
+    pub fn default_issue_fields<F>(&mut self, fields: Vec<F>) -> &mut JiraBuilder
+    where
+        F: Into<String>,
+    {
+        self.default_issue_fields = Some(fields.into_iter().map(|f| f.into()).collect());
+        self
+    }
+
+    /// See [`Jira::with_middleware`]
+    pub fn middleware<M>(&mut self, middleware: M) -> &mut JiraBuilder
+    where
+        M: RequestMiddleware + 'static,
+    {
+        self.middlewares.push(std::sync::Arc::new(middleware));
+        self
+    }
+
+    /// See [`Jira::set_read_only`]
+    pub fn read_only(&mut self, read_only: bool) -> &mut JiraBuilder {
+        self.read_only = read_only;
+        self
+    }
+
+    pub fn build(&self) -> Result<Jira> {
+        let client = match self.http_client {
+            Some(ref client) => client.clone(),
+            None => {
+                let mut client = Client::builder();
+                if let Some(timeout) = self.timeout {
+                    client = client.timeout(timeout);
+                }
+                if let Some(ref user_agent) = self.user_agent {
+                    client = client.user_agent(user_agent);
+                }
+                if let Some(max) = self.pool_max_idle_per_host {
+                    client = client.pool_max_idle_per_host(max);
+                }
+                if let Some(timeout) = self.pool_idle_timeout {
+                    client = client.pool_idle_timeout(timeout);
+                }
+                client.build()?
+            }
+        };
+
+        let mut jira = Jira::from_client(&self.host, self.credentials.clone(), client)?;
+        if let Some(ref base_path) = self.base_path {
+            jira = jira.with_base_path(base_path);
+        }
+        for (api_name, path) in &self.api_paths {
+            jira = jira.with_base_api_path(api_name, path);
+        }
+        if let Some(ref fields) = self.default_issue_fields {
+            jira = jira.with_default_issue_fields(fields.clone());
+        }
+        std::sync::Arc::make_mut(&mut jira.core).middlewares = self.middlewares.clone();
+        jira.set_read_only(self.read_only);
+        Ok(jira)
+    }
+}
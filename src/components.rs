@@ -1,13 +1,34 @@
 //! Interfaces for accessing and managing components
 
 // Ours
-use crate::{Component, Jira, Result};
+use crate::{Component, EmptyResponse, Jira, Result};
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Default)]
 pub struct CreateComponent {
     pub name: String,
     pub description: Option<String>,
     pub project: String,
+    /// Lead of the component on Jira Cloud
+    #[serde(
+        default,
+        rename = "leadAccountId",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub lead_account_id: Option<String>,
+    /// Lead of the component on Jira Server and Data Center
+    #[serde(
+        default,
+        rename = "leadUserName",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub lead_user_name: Option<String>,
+    /// Who new issues are assigned to, e.g. `PROJECT_DEFAULT` or `COMPONENT_LEAD`
+    #[serde(
+        default,
+        rename = "assigneeType",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub assignee_type: Option<String>,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -61,6 +82,19 @@ impl Components {
             .put("api", &format!("/component/{}", id.into()), data)
     }
 
+    /// Delete a component
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/component-delete)
+    /// for more information
+    pub fn delete<I>(&self, id: I) -> Result<()>
+    where
+        I: Into<String>,
+    {
+        self.jira
+            .delete::<EmptyResponse>("api", &format!("/component/{}", id.into()))
+            .map(|_| ())
+    }
+
     /// Returns all components of a project
     ///
     /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/project-getProjectComponents)
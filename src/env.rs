@@ -0,0 +1,61 @@
+//! Reads the client configuration from the standard Atlassian environment variables
+
+// Ours
+use crate::{Credentials, Error, Result};
+
+/// Reads the host and credentials from the process environment
+pub(crate) fn from_env() -> Result<(String, Credentials)> {
+    from_lookup(|name| std::env::var(name).ok())
+}
+
+fn from_lookup<F>(lookup: F) -> Result<(String, Credentials)>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    // empty variables are treated like unset ones
+    let var = |name: &str| lookup(name).filter(|value| !value.trim().is_empty());
+
+    let host = var("JIRA_URL")
+        .or_else(|| var("JIRA_HOST"))
+        .ok_or_else(|| Error::InvalidInput("neither JIRA_URL nor JIRA_HOST is set".to_owned()))?;
+
+    let mut found = vec![];
+    match (var("JIRA_USER"), var("JIRA_TOKEN")) {
+        (Some(user), Some(token)) => {
+            found.push(("JIRA_USER/JIRA_TOKEN", Credentials::Basic(user, token)))
+        }
+        (Some(_), None) => {
+            return Err(Error::InvalidInput(
+                "JIRA_USER is set without JIRA_TOKEN".to_owned(),
+            ))
+        }
+        (None, Some(_)) => {
+            return Err(Error::InvalidInput(
+                "JIRA_TOKEN is set without JIRA_USER".to_owned(),
+            ))
+        }
+        (None, None) => (),
+    }
+    if let Some(token) = var("JIRA_PAT") {
+        found.push(("JIRA_PAT", Credentials::PersonalAccessToken(token)));
+    }
+    if let Some(cookie) = var("JIRA_COOKIE") {
+        found.push(("JIRA_COOKIE", Credentials::Cookie(cookie)));
+    }
+
+    match found.len() {
+        0 => Err(Error::InvalidInput(
+            "no credentials set, expected JIRA_USER and JIRA_TOKEN, JIRA_PAT or JIRA_COOKIE"
+                .to_owned(),
+        )),
+        1 => Ok((host, found.remove(0).1)),
+        _ => Err(Error::InvalidInput(format!(
+            "conflicting credentials set: {}",
+            found
+                .iter()
+                .map(|(names, _)| *names)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))),
+    }
+}
@@ -28,6 +28,96 @@ pub enum Error {
     NotFound,
     /// URI parse error
     ParseError(ParseError),
+    /// Invalid client configuration or arguments, detected before sending a request
+    InvalidInput(String),
+}
+
+/// Broad kinds of failures, see [`Error::category`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCategory {
+    /// Missing or invalid credentials
+    Authentication,
+    /// The user isn't allowed to perform the operation
+    Permission,
+    /// The resource doesn't exist or isn't visible to the user
+    NotFound,
+    /// The request was rejected because of its content, e.g. invalid fields
+    Validation,
+    /// The operation isn't valid for the workflow state of an issue
+    Workflow,
+    /// Too many requests were sent
+    RateLimited,
+    /// Jira failed to handle a valid request
+    Server,
+    /// Jira couldn't be reached or didn't respond in time
+    Network,
+    /// Any other failure, e.g. a response which couldn't be parsed
+    Other,
+}
+
+impl Error {
+    /// Classifies the error, e.g. to branch on permission or workflow
+    /// failures without matching the messages of Jira at each call site
+    ///
+    /// The status code decides where it is conclusive. Otherwise the error
+    /// messages are checked for common phrases, as Jira reports permission
+    /// and workflow problems with a 400 as well.
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            Error::Unauthorized => ErrorCategory::Authentication,
+            Error::NotFound => ErrorCategory::NotFound,
+            Error::InvalidInput(_) => ErrorCategory::Validation,
+            Error::Http(e) if e.is_timeout() || e.is_connect() => ErrorCategory::Network,
+            Error::Fault { code, errors } => fault_category(*code, errors),
+            _ => ErrorCategory::Other,
+        }
+    }
+
+    /// Whether the error is likely transient, so that repeating the request may succeed
+    ///
+    /// This is the case for rate limiting (429), gateway errors (502, 503, 504),
+    /// timeouts and failures to connect.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Http(e) => e.is_timeout() || e.is_connect(),
+            Error::Fault { code, .. } => matches!(
+                *code,
+                StatusCode::TOO_MANY_REQUESTS
+                    | StatusCode::BAD_GATEWAY
+                    | StatusCode::SERVICE_UNAVAILABLE
+                    | StatusCode::GATEWAY_TIMEOUT
+            ),
+            _ => false,
+        }
+    }
+}
+
+fn fault_category(code: StatusCode, errors: &Errors) -> ErrorCategory {
+    match code {
+        StatusCode::UNAUTHORIZED => return ErrorCategory::Authentication,
+        StatusCode::NOT_FOUND => return ErrorCategory::NotFound,
+        StatusCode::TOO_MANY_REQUESTS => return ErrorCategory::RateLimited,
+        code if code.is_server_error() => return ErrorCategory::Server,
+        _ => (),
+    }
+    let messages = errors.all_messages().join("\n").to_lowercase();
+    let mentions = |phrases: &[&str]| phrases.iter().any(|phrase| messages.contains(phrase));
+    if mentions(&["not permitted", "permission", "not allowed to"]) {
+        ErrorCategory::Permission
+    } else if mentions(&["workflow", "transition"]) {
+        ErrorCategory::Workflow
+    } else if mentions(&["does not exist", "not found"]) {
+        ErrorCategory::NotFound
+    } else if code == StatusCode::FORBIDDEN {
+        ErrorCategory::Permission
+    } else if code == StatusCode::BAD_REQUEST
+        || code == StatusCode::UNPROCESSABLE_ENTITY
+        || !errors.errors.is_empty()
+    {
+        ErrorCategory::Validation
+    } else {
+        ErrorCategory::Other
+    }
 }
 
 impl From<ParseError> for Error {
@@ -65,11 +155,12 @@ impl ::std::fmt::Display for Error {
             Fault {
                 ref code,
                 ref errors,
-            } => writeln!(f, "Jira Client Error ({code}):\n{errors:#?}"),
+            } => writeln!(f, "Jira Client Error ({code}): {errors}"),
             ParseError(ref e) => writeln!(f, "Could not connect to Jira: {e:?}!"),
             Unauthorized => writeln!(f, "Could not connect to Jira: Unauthorized"),
             MethodNotAllowed => writeln!(f, "Jira request error: MethodNotAllowed"),
             NotFound => writeln!(f, "Jira request error: NotFound"),
+            InvalidInput(ref message) => writeln!(f, "Invalid input: {message}"),
         }
     }
 }
@@ -82,7 +173,7 @@ impl ::std::error::Error for Error {
             Http(ref e) => Some(e),
             IO(ref e) => Some(e),
             Serde(ref e) => Some(e),
-            Fault { .. } => None,
+            Fault { ref errors, .. } => Some(errors),
             _ => None,
         }
     }
@@ -3,11 +3,14 @@
 // Third party
 use serde::Serialize;
 use std::collections::BTreeMap;
+use time::OffsetDateTime;
 use url::form_urlencoded;
 
 // Ours
 use crate::{
-    Board, Changelog, Comment, Issue, IssueType, Jira, Priority, Project, Result, SearchOptions,
+    Attachment, Board, Changelog, Comment, EmptyResponse, Error, Errors, History, HistoryItem,
+    Issue, IssueType, Jira, PageFetcher, PageIter, Paginated, Priority, Project, Result,
+    SearchOptions, TransitionTriggerOptions, User, Visibility, Votes,
 };
 
 /// Issue options
@@ -25,19 +28,38 @@ pub struct Assignee {
 pub struct Component {
     pub id: String,
     pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Key of the project, only present when the component is fetched directly
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lead: Option<User>,
+    #[serde(
+        default,
+        rename = "assigneeType",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub assignee_type: Option<String>,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Fields {
-    pub assignee: Assignee,
-    pub components: Vec<Component>,
-    pub description: String,
-    pub environment: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<Assignee>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<Component>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub environment: Option<String>,
     pub issuetype: IssueType,
-    pub priority: Priority,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<Priority>,
     pub project: Project,
-    pub reporter: Assignee,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reporter: Option<Assignee>,
     pub summary: String,
 }
 
@@ -51,6 +73,121 @@ pub struct CreateCustomIssue<CustomFields> {
     pub fields: CustomFields,
 }
 
+impl CreateCustomIssue<BTreeMap<String, ::serde_json::Value>> {
+    /// Return a new instance of a builder for a minimal issue creation payload
+    pub fn builder<P, T, S>(project_key: P, issue_type: T, summary: S) -> CreateIssueBuilder
+    where
+        P: Into<String>,
+        T: Into<String>,
+        S: Into<String>,
+    {
+        CreateIssueBuilder::new(project_key, issue_type, summary)
+    }
+}
+
+/// A builder interface for issue creation which only serializes the fields
+/// that were set. Typically this is initialized with `CreateCustomIssue::builder()`
+#[derive(Debug)]
+pub struct CreateIssueBuilder {
+    fields: BTreeMap<String, ::serde_json::Value>,
+    /// First field value which failed to serialize, reported by `build`
+    error: Option<String>,
+}
+
+impl CreateIssueBuilder {
+    /// Creates a new instance referencing the project by key and the issue type by name
+    pub fn new<P, T, S>(project_key: P, issue_type: T, summary: S) -> CreateIssueBuilder
+    where
+        P: Into<String>,
+        T: Into<String>,
+        S: Into<String>,
+    {
+        let mut builder = CreateIssueBuilder {
+            fields: BTreeMap::new(),
+            error: None,
+        };
+        builder
+            .field(
+                "project",
+                ::serde_json::json!({ "key": project_key.into() }),
+            )
+            .field(
+                "issuetype",
+                ::serde_json::json!({ "name": issue_type.into() }),
+            )
+            .field("summary", summary.into());
+        builder
+    }
+
+    /// Appends a field to set on creation
+    ///
+    /// A value which fails to serialize, e.g. a map with non-string keys, is
+    /// reported by [`CreateIssueBuilder::build`].
+    pub fn field<N, V>(&mut self, name: N, value: V) -> &mut CreateIssueBuilder
+    where
+        N: Into<String>,
+        V: Serialize,
+    {
+        let name = name.into();
+        match serde_json::to_value(value) {
+            Ok(value) => {
+                self.fields.insert(name, value);
+            }
+            Err(error) => {
+                self.error.get_or_insert_with(|| {
+                    format!("field {name} could not be serialized: {error}")
+                });
+            }
+        }
+        self
+    }
+
+    pub fn description<D>(&mut self, description: D) -> &mut CreateIssueBuilder
+    where
+        D: Into<String>,
+    {
+        self.field("description", description.into())
+    }
+
+    pub fn assignee<N>(&mut self, name: N) -> &mut CreateIssueBuilder
+    where
+        N: Into<String>,
+    {
+        self.field("assignee", Assignee { name: name.into() })
+    }
+
+    pub fn priority<N>(&mut self, name: N) -> &mut CreateIssueBuilder
+    where
+        N: Into<String>,
+    {
+        self.field("priority", ::serde_json::json!({ "name": name.into() }))
+    }
+
+    pub fn labels<L>(&mut self, labels: Vec<L>) -> &mut CreateIssueBuilder
+    where
+        L: Into<String>,
+    {
+        self.field(
+            "labels",
+            labels
+                .into_iter()
+                .map(|l| l.into())
+                .collect::<Vec<String>>(),
+        )
+    }
+
+    /// Returns the creation payload, or an [`Error::InvalidInput`] if a field
+    /// failed to serialize
+    pub fn build(&self) -> Result<CreateCustomIssue<BTreeMap<String, ::serde_json::Value>>> {
+        match self.error {
+            Some(ref error) => Err(Error::InvalidInput(error.clone())),
+            None => Ok(CreateCustomIssue {
+                fields: self.fields.clone(),
+            }),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CreateResponse {
     pub id: String,
@@ -59,11 +196,195 @@ pub struct CreateResponse {
     pub url: String,
 }
 
+/// Fields which can be set when creating issues, as returned by createmeta
+#[derive(Debug, Deserialize, Clone)]
+pub struct CreateMeta {
+    pub projects: Vec<CreateMetaProject>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CreateMetaProject {
+    pub id: String,
+    pub key: String,
+    pub name: String,
+    #[serde(default)]
+    pub issuetypes: Vec<CreateMetaIssueType>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CreateMetaIssueType {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub subtask: bool,
+    /// Fields of the create screen by field id, e.g. `summary` or `customfield_10001`
+    #[serde(default)]
+    pub fields: BTreeMap<String, FieldMeta>,
+}
+
+impl CreateMetaIssueType {
+    /// Lists the problems Jira would reject the given issue fields for
+    ///
+    /// Required fields without a default must be set, fields restricted to
+    /// allowed values must use one of them, matched by `id`, `key`, `name` or
+    /// `value`, and only fields of the create screen may be set.
+    pub fn validate(&self, fields: &serde_json::Map<String, ::serde_json::Value>) -> Vec<String> {
+        validate_fields(&self.fields, fields, "the create screen")
+    }
+}
+
+/// Checks field values against the fields of a create or transition screen
+pub(crate) fn validate_fields<'a, F>(
+    screen: &BTreeMap<String, FieldMeta>,
+    fields: F,
+    screen_name: &str,
+) -> Vec<String>
+where
+    F: IntoIterator<Item = (&'a String, &'a ::serde_json::Value)>,
+{
+    let fields = fields.into_iter().collect::<BTreeMap<_, _>>();
+    let mut problems = vec![];
+    for (id, meta) in screen {
+        match fields.get(id).filter(|value| !is_blank(value)) {
+            None if meta.required && !meta.has_default_value => {
+                problems.push(format!("missing required field {} ({})", meta.name, id))
+            }
+            Some(value) if !meta.allowed_values.is_empty() => {
+                let values = match value {
+                    ::serde_json::Value::Array(values) => values.iter().collect(),
+                    value => vec![*value],
+                };
+                for value in values {
+                    if !meta
+                        .allowed_values
+                        .iter()
+                        .any(|allowed| is_allowed(value, allowed))
+                    {
+                        problems.push(format!(
+                            "value {} is not allowed for field {} ({})",
+                            value, meta.name, id
+                        ));
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+    for id in fields.keys() {
+        if !screen.contains_key(*id) {
+            problems.push(format!("field {} is not on {}", id, screen_name));
+        }
+    }
+    problems
+}
+
+/// Describes a field of a create or transition screen
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldMeta {
+    pub required: bool,
+    pub name: String,
+    #[serde(default)]
+    pub has_default_value: bool,
+    #[serde(default)]
+    pub allowed_values: Vec<::serde_json::Value>,
+}
+
+/// Picks the query parameter identifying a project or issue type reference,
+/// preferring the first of the given `(parameter, key)` pairs
+fn identify(
+    reference: &::serde_json::Value,
+    preferred: (&'static str, &str),
+    fallback: (&'static str, &str),
+) -> Option<(&'static str, String)> {
+    [preferred, fallback].into_iter().find_map(|(param, key)| {
+        reference
+            .get(key)
+            .and_then(|value| value.as_str())
+            .filter(|value| !value.trim().is_empty())
+            .map(|value| (param, value.to_owned()))
+    })
+}
+
+fn is_blank(value: &::serde_json::Value) -> bool {
+    match value {
+        ::serde_json::Value::Null => true,
+        ::serde_json::Value::String(value) => value.trim().is_empty(),
+        ::serde_json::Value::Array(values) => values.is_empty(),
+        _ => false,
+    }
+}
+
+/// Whether a field value references the allowed value, comparing all
+/// identifying keys the two have in common
+fn is_allowed(value: &::serde_json::Value, allowed: &::serde_json::Value) -> bool {
+    const KEYS: [&str; 4] = ["id", "key", "name", "value"];
+    match value {
+        ::serde_json::Value::Object(given) => {
+            let mut shared = KEYS
+                .iter()
+                .filter_map(|key| Some((given.get(*key)?, allowed.get(*key)?)))
+                .filter(|(given, _)| !is_blank(given))
+                .peekable();
+            shared.peek().is_some() && shared.all(|(given, allowed)| given == allowed)
+        }
+        ::serde_json::Value::String(given) => KEYS
+            .iter()
+            .any(|key| allowed.get(*key).and_then(|v| v.as_str()) == Some(given)),
+        _ => true,
+    }
+}
+
+#[derive(Serialize, Debug)]
+struct BulkCreateIssues<CustomFields> {
+    #[serde(rename = "issueUpdates")]
+    issue_updates: Vec<CreateCustomIssue<CustomFields>>,
+}
+
+/// Result of a bulk issue creation, which may partially fail
+#[derive(Debug, Deserialize)]
+pub struct BulkCreateResponse {
+    pub issues: Vec<CreateResponse>,
+    #[serde(default)]
+    pub errors: Vec<BulkCreateError>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkCreateError {
+    pub status: u16,
+    pub element_errors: Errors,
+    /// Index of the issue in the request which failed
+    pub failed_element_number: usize,
+}
+
+/// Result of a bulk transition, listing the issues which failed to transition
+#[derive(Debug)]
+pub struct BulkTransitionReport {
+    /// Keys of the issues which were transitioned, in request order
+    pub transitioned: Vec<String>,
+    pub errors: Vec<BulkTransitionError>,
+}
+
+#[derive(Debug)]
+pub struct BulkTransitionError {
+    pub key: String,
+    pub error: Error,
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct EditIssue<T: Serialize> {
     pub fields: BTreeMap<String, T>,
 }
 
+/// An edited issue together with the changes of the edit
+#[derive(Debug)]
+pub struct UpdateResult {
+    pub issue: Issue,
+    /// Items of the changelog entries added by the edit
+    pub changes: Vec<HistoryItem>,
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct EditCustomIssue<CustomFields> {
     pub fields: CustomFields,
@@ -80,9 +401,76 @@ pub struct IssueResults {
     pub issues: Vec<Issue>,
 }
 
+/// Maximum number of issues the agile API accepts in one epic move request
+const EPIC_BATCH_SIZE: usize = 50;
+
+#[derive(Serialize, Debug)]
+struct EpicIssues<'a> {
+    issues: &'a [String],
+}
+
+/// Options for cloning an issue with `Issues::clone_issue`
+#[derive(Debug, Default, Clone)]
+pub struct CloneOptions {
+    /// Prefix prepended to the summary of the clone, e.g. `"CLONE - "`
+    pub summary_prefix: Option<String>,
+    /// Re-upload the attachments of the source issue onto the clone
+    pub copy_attachments: bool,
+    /// Recreate the issue links of the source issue on the clone
+    pub copy_links: bool,
+    /// Key of the project to create the clone in, defaults to the source project
+    pub target_project: Option<String>,
+}
+
+/// Input for `Issues::create_subtask`
+#[derive(Debug, Clone)]
+pub struct SubtaskInput {
+    pub summary: String,
+    /// Name of the subtask issue type, `Sub-task` by default
+    pub issue_type: String,
+    pub description: Option<String>,
+    /// Any further fields to set, keyed by field id
+    pub fields: BTreeMap<String, ::serde_json::Value>,
+}
+
+impl SubtaskInput {
+    pub fn new<S>(summary: S) -> SubtaskInput
+    where
+        S: Into<String>,
+    {
+        SubtaskInput {
+            summary: summary.into(),
+            issue_type: "Sub-task".to_owned(),
+            description: None,
+            fields: BTreeMap::new(),
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct AddComment {
     pub body: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+}
+
+impl AddComment {
+    /// Creates a comment visible to everyone who can see the issue
+    pub fn new<B>(body: B) -> AddComment
+    where
+        B: Into<String>,
+    {
+        AddComment {
+            body: body.into(),
+            visibility: None,
+        }
+    }
+
+    /// Restricts the comment, e.g. with `Visibility::role("Administrators")`
+    pub fn with_visibility(mut self, visibility: Visibility) -> AddComment {
+        self.visibility = Some(visibility);
+        self
+    }
 }
 
 impl Issues {
@@ -98,7 +486,61 @@ impl Issues {
     where
         I: Into<String>,
     {
-        self.jira.get("api", &format!("/issue/{}", id.into()))
+        self.get_with_options(id, &SearchOptions::default())
+    }
+
+    /// Get a single issue, restricting the returned fields or expanding
+    /// additional data with the given options
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-getIssue)
+    /// for more information
+    pub fn get_with_options<I>(&self, id: I, options: &SearchOptions) -> Result<Issue>
+    where
+        I: Into<String>,
+    {
+        let options = self.jira.issue_options(options);
+        let mut path = vec![format!("/issue/{}", id.into())];
+        if let Some(query) = options.serialize() {
+            path.push(query);
+        }
+        let mut issue: Issue = self.jira.get("api", path.join("?").as_ref())?;
+        issue.requested_fields = Some(options.fetched_fields());
+        Ok(issue)
+    }
+
+    /// Get a single issue, or `None` if it doesn't exist
+    ///
+    /// Jira also responds with a 404 to issues the user isn't allowed to
+    /// browse, so these are reported as `None` as well. Other failures are
+    /// returned as errors.
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-getIssue)
+    /// for more information
+    pub fn try_get<I>(&self, id: I, options: &SearchOptions) -> Result<Option<Issue>>
+    where
+        I: Into<String>,
+    {
+        match self.get_with_options(id, options) {
+            Ok(issue) => Ok(Some(issue)),
+            Err(Error::NotFound) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Returns the attachments of an issue, fetching only its `attachment` field
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-getIssue)
+    /// for more information
+    pub fn list_attachments<I>(&self, id: I) -> Result<Vec<Attachment>>
+    where
+        I: Into<String>,
+    {
+        let options = SearchOptions::builder().fields(vec!["attachment"]).build();
+        let issue = self.get_with_options(id, &options)?;
+        Ok(issue
+            .field::<Vec<Attachment>>("attachment")
+            .transpose()?
+            .unwrap_or_default())
     }
 
     /// Get a single custom issue
@@ -132,6 +574,243 @@ impl Issues {
         self.jira.post("api", "/issue", data)
     }
 
+    /// Create a new issue and fetch it with the given options
+    ///
+    /// This takes two requests, one creating the issue and one getting it,
+    /// see [`Issues::get_with_options`]. Works with [`CreateIssue`] as well as
+    /// [`CreateCustomIssue`].
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-createIssue)
+    /// for more information
+    pub fn create_and_get<D>(&self, data: D, options: &SearchOptions) -> Result<Issue>
+    where
+        D: Serialize,
+    {
+        let created: CreateResponse = self.jira.post("api", "/issue", data)?;
+        self.get_with_options(created.key, options)
+    }
+
+    /// Create a new issue after checking it against the createmeta of its
+    /// project and issue type
+    ///
+    /// Missing required fields, values outside the allowed ones and fields
+    /// which aren't on the create screen are reported together as an
+    /// [`Error::InvalidInput`] without calling create. The createmeta is
+    /// requested once per project and issue type and shared by all copies
+    /// of the client. Works with [`CreateIssue`] as well as [`CreateCustomIssue`].
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-getCreateIssueMeta)
+    /// for more information
+    pub fn create_validated<D>(&self, data: D) -> Result<CreateResponse>
+    where
+        D: Serialize,
+    {
+        let data = serde_json::to_value(data)?;
+        let fields = data
+            .get("fields")
+            .and_then(|fields| fields.as_object())
+            .ok_or_else(|| Error::InvalidInput("the issue has no fields".to_owned()))?;
+
+        let project = match fields.get("project") {
+            Some(project) => identify(project, ("projectKeys", "key"), ("projectIds", "id")),
+            None => None,
+        }
+        .ok_or_else(|| Error::InvalidInput("the issue has no project".to_owned()))?;
+        let issue_type = match fields.get("issuetype") {
+            Some(issue_type) => identify(
+                issue_type,
+                ("issuetypeIds", "id"),
+                ("issuetypeNames", "name"),
+            ),
+            None => None,
+        }
+        .ok_or_else(|| Error::InvalidInput("the issue has no issue type".to_owned()))?;
+
+        let query = form_urlencoded::Serializer::new(String::new())
+            .append_pair(project.0, &project.1)
+            .append_pair(issue_type.0, &issue_type.1)
+            .finish();
+        let meta = self.cached_create_meta(&query, &project.1, &issue_type.1)?;
+
+        let problems = meta.validate(fields);
+        if !problems.is_empty() {
+            return Err(Error::InvalidInput(format!(
+                "issue rejected by createmeta: {}",
+                problems.join("; ")
+            )));
+        }
+        self.jira.post("api", "/issue", data)
+    }
+
+    /// Get the fields which can be set when creating issues of a type in a project
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-getCreateIssueMeta)
+    /// for more information
+    pub fn get_create_meta<P, T>(&self, project_key: P, issue_type: T) -> Result<CreateMeta>
+    where
+        P: Into<String>,
+        T: Into<String>,
+    {
+        let query = form_urlencoded::Serializer::new(String::new())
+            .append_pair("projectKeys", &project_key.into())
+            .append_pair("issuetypeNames", &issue_type.into())
+            .finish();
+        self.fetch_create_meta(&query)
+    }
+
+    fn fetch_create_meta(&self, query: &str) -> Result<CreateMeta> {
+        let query = form_urlencoded::Serializer::new(query.to_owned())
+            .append_pair("expand", "projects.issuetypes.fields")
+            .finish();
+        self.jira
+            .get("api", &format!("/issue/createmeta?{}", query))
+    }
+
+    fn cached_create_meta(
+        &self,
+        query: &str,
+        project: &str,
+        issue_type: &str,
+    ) -> Result<CreateMetaIssueType> {
+        if let Some(meta) = self.jira.create_meta.lock().unwrap().get(query) {
+            return Ok(meta.clone());
+        }
+        let meta = self
+            .fetch_create_meta(query)?
+            .projects
+            .into_iter()
+            .next()
+            .and_then(|project| project.issuetypes.into_iter().next())
+            .ok_or_else(|| {
+                Error::InvalidInput(format!(
+                    "project {} has no issue type {}",
+                    project, issue_type
+                ))
+            })?;
+        self.jira
+            .create_meta
+            .lock()
+            .unwrap()
+            .insert(query.to_owned(), meta.clone());
+        Ok(meta)
+    }
+
+    /// Create a subtask of an existing issue
+    ///
+    /// The subtask is created in the project of the parent, which is looked
+    /// up first. Fails with an [`Error::InvalidInput`] if the parent has no
+    /// project.
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-createIssue)
+    /// for more information
+    pub fn create_subtask<K>(&self, parent_key: K, input: SubtaskInput) -> Result<CreateResponse>
+    where
+        K: Into<String>,
+    {
+        // ask for the project explicitly, default issue fields may leave it out
+        let parent = self.get_with_options(
+            parent_key,
+            &SearchOptions::builder().fields(vec!["project"]).build(),
+        )?;
+        let project = parent.project().map(|project| project.key).ok_or_else(|| {
+            Error::InvalidInput(format!("parent issue {} has no project", parent.key))
+        })?;
+
+        let mut builder = CreateIssueBuilder::new(project, input.issue_type, input.summary);
+        builder.field("parent", ::serde_json::json!({ "key": parent.key }));
+        if let Some(description) = input.description {
+            builder.description(description);
+        }
+        for (name, value) in input.fields {
+            builder.field(name, value);
+        }
+        self.create_from_custom_issue(builder.build()?)
+    }
+
+    /// Create several custom issues in one request
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-createIssues)
+    /// for more information
+    pub fn bulk_create_custom<T: serde::Serialize>(
+        &self,
+        issues: Vec<CreateCustomIssue<T>>,
+    ) -> Result<BulkCreateResponse> {
+        self.jira.post(
+            "api",
+            "/issue/bulk",
+            BulkCreateIssues {
+                issue_updates: issues,
+            },
+        )
+    }
+
+    /// Transition an issue to the status with the given name
+    ///
+    /// The available transitions are listed first and the one leading to
+    /// `status_name`, compared case-insensitively, is triggered with the given
+    /// fields. Fails with an [`Error::InvalidInput`] naming the reachable
+    /// statuses if no transition leads there.
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-doTransition)
+    /// for more information
+    pub fn transition_to<K>(
+        &self,
+        key: K,
+        status_name: &str,
+        fields: Option<BTreeMap<String, ::serde_json::Value>>,
+    ) -> Result<()>
+    where
+        K: Into<String>,
+    {
+        let key = key.into();
+        let transitions = self.jira.transitions(key.as_str());
+        let available = transitions.list()?;
+        let transition = available
+            .iter()
+            .find(|transition| transition.to.name.eq_ignore_ascii_case(status_name))
+            .ok_or_else(|| {
+                Error::InvalidInput(format!(
+                    "no transition of {} leads to {}, reachable statuses: {}",
+                    key,
+                    status_name,
+                    available
+                        .iter()
+                        .map(|transition| transition.to.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ))
+            })?;
+
+        let mut options = TransitionTriggerOptions::new(transition.id.as_str());
+        options.fields = fields.unwrap_or_default();
+        transitions.trigger(options)
+    }
+
+    /// Transition several issues, one request per issue
+    ///
+    /// Jira has no bulk transition endpoint, so the transitions are applied in
+    /// order and a failing one doesn't stop the others. Failures are reported
+    /// per issue in the returned [`BulkTransitionReport`].
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-doTransition)
+    /// for more information
+    pub fn bulk_transition(
+        &self,
+        transitions: Vec<(String, TransitionTriggerOptions)>,
+    ) -> Result<BulkTransitionReport> {
+        let mut report = BulkTransitionReport {
+            transitioned: vec![],
+            errors: vec![],
+        };
+        for (key, options) in transitions {
+            match self.jira.transitions(key.as_str()).trigger(options) {
+                Ok(()) => report.transitioned.push(key),
+                Err(error) => report.errors.push(BulkTransitionError { key, error }),
+            }
+        }
+        Ok(report)
+    }
+
     /// Edit an issue
     ///
     /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-editIssue)
@@ -144,6 +823,32 @@ impl Issues {
         self.jira.put("api", &format!("/issue/{}", id.into()), data)
     }
 
+    /// Edit an issue and return it together with the changes Jira recorded
+    ///
+    /// The length of the issue's changelog is noted before the edit, and the
+    /// items of all entries added after it are returned as the changes. An
+    /// edit which changes nothing records no entry and returns no changes.
+    /// Entries others add while the edit is in flight are included as well.
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-editIssue)
+    /// for more information
+    pub fn edit_and_return<I, T>(&self, id: I, data: EditIssue<T>) -> Result<UpdateResult>
+    where
+        I: Into<String>,
+        T: Serialize,
+    {
+        let id = id.into();
+        let recorded = self.changelog_len(&id)?;
+        self.edit(id.as_str(), data)?;
+        let changes = self
+            .changelog_from(&id, recorded)?
+            .into_iter()
+            .flat_map(|history| history.items)
+            .collect();
+        let issue = self.get(id)?;
+        Ok(UpdateResult { issue, changes })
+    }
+
     /// Edit an issue
     ///
     /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-editIssue)
@@ -161,14 +866,21 @@ impl Issues {
     /// See this [jira docs](https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/board-getIssuesForBoard)
     /// for more information
     pub fn list(&self, board: &Board, options: &SearchOptions) -> Result<IssueResults> {
+        let options = self.jira.issue_options(options);
         let mut path = vec![format!("/board/{}/issue", board.id)];
         let query_options = options.serialize().unwrap_or_default();
         let query = form_urlencoded::Serializer::new(query_options).finish();
 
         path.push(query);
 
-        self.jira
-            .get::<IssueResults>("agile", path.join("?").as_ref())
+        let mut results = self
+            .jira
+            .get::<IssueResults>("agile", path.join("?").as_ref())?;
+        let fields = Some(options.listed_fields());
+        for issue in &mut results.issues {
+            issue.requested_fields = fields.clone();
+        }
+        Ok(results)
     }
 
     /// Returns a type which may be used to iterate over consecutive pages of results
@@ -197,56 +909,365 @@ impl Issues {
         self.jira
             .get("api", format!("/issue/{}/changelog", key.into()).as_ref())
     }
+
+    /// Returns the history entries of an issue created after `since`, e.g. to
+    /// sync changes incrementally
+    ///
+    /// Requests all pages of the changelog. Entries whose `created` can't be
+    /// parsed are kept, so that no change is missed.
+    ///
+    /// See this [jira docs](https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-issues/#api-rest-api-2-issue-issueidorkey-changelog-get)
+    /// for more information
+    pub fn changelog_since<K>(&self, key: K, since: OffsetDateTime) -> Result<Vec<History>>
+    where
+        K: Into<String>,
+    {
+        let histories = self.changelog_from(&key.into(), 0)?;
+        Ok(histories
+            .into_iter()
+            .filter(|history| history.created_at().is_none_or(|created| created > since))
+            .collect())
+    }
+
+    /// Number of history entries of an issue, i.e. where new entries start
+    fn changelog_len(&self, key: &str) -> Result<u64> {
+        let page = self
+            .jira
+            .get::<ChangelogPage>("api", &format!("/issue/{key}/changelog?maxResults=1"))?;
+        match page.total {
+            Some(total) => Ok(total),
+            None => Ok(self.changelog_from(key, 0)?.len() as u64),
+        }
+    }
+
+    /// History entries of an issue from position `start_at` on, oldest first
+    fn changelog_from(&self, key: &str, mut start_at: u64) -> Result<Vec<History>> {
+        let mut histories = vec![];
+        loop {
+            let page = self.jira.get::<ChangelogPage>(
+                "api",
+                &format!("/issue/{key}/changelog?startAt={start_at}"),
+            )?;
+            let has_more = page.has_more();
+            start_at = page.start_at + page.values.len() as u64;
+            histories.extend(page.values);
+            if !has_more {
+                return Ok(histories);
+            }
+        }
+    }
+
+    /// Move issues into an epic
+    ///
+    /// Large batches are sent in chunks of 50 issues, the most the agile API
+    /// accepts in one request.
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/epic-moveIssuesToEpic)
+    /// for more information
+    pub fn add_issues_to_epic<K>(&self, epic_key: K, issue_keys: Vec<String>) -> Result<()>
+    where
+        K: Into<String>,
+    {
+        self.move_to_epic(&format!("/epic/{}/issue", epic_key.into()), &issue_keys)
+    }
+
+    /// Remove issues from whichever epic they belong to
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/epic-removeIssuesFromEpic)
+    /// for more information
+    pub fn remove_issues_from_epic(&self, issue_keys: Vec<String>) -> Result<()> {
+        self.move_to_epic("/epic/none/issue", &issue_keys)
+    }
+
+    fn move_to_epic(&self, path: &str, issue_keys: &[String]) -> Result<()> {
+        for issues in issue_keys.chunks(EPIC_BATCH_SIZE) {
+            self.jira
+                .post::<EmptyResponse, _>("agile", path, EpicIssues { issues })?;
+        }
+        Ok(())
+    }
+
+    /// Start watching an issue as the authenticated user
+    ///
+    /// The user is resolved with `/myself` once per client. On Jira Cloud the
+    /// account id is used, elsewhere the user name.
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-addWatcher)
+    /// for more information
+    pub fn watch<K>(&self, key: K) -> Result<()>
+    where
+        K: Into<String>,
+    {
+        let (_, user) = self.watcher()?;
+        self.jira
+            .post::<EmptyResponse, _>("api", &format!("/issue/{}/watchers", key.into()), user)
+            .map(|_| ())
+    }
+
+    /// Stop watching an issue as the authenticated user
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-removeWatcher)
+    /// for more information
+    pub fn unwatch<K>(&self, key: K) -> Result<()>
+    where
+        K: Into<String>,
+    {
+        let (param, user) = self.watcher()?;
+        let query = form_urlencoded::Serializer::new(String::new())
+            .append_pair(param, &user)
+            .finish();
+        self.jira
+            .delete::<EmptyResponse>("api", &format!("/issue/{}/watchers?{}", key.into(), query))
+            .map(|_| ())
+    }
+
+    /// Get the vote count of an issue and whether the authenticated user voted
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-getVotes)
+    /// for more information
+    pub fn vote_status<K>(&self, key: K) -> Result<Votes>
+    where
+        K: Into<String>,
+    {
+        self.jira
+            .get("api", &format!("/issue/{}/votes", key.into()))
+    }
+
+    /// Identifies the authenticated user the way the watcher endpoints expect
+    fn watcher(&self) -> Result<(&'static str, String)> {
+        let myself = self.jira.cached_myself()?;
+        match (myself.account_id, myself.name) {
+            (Some(account_id), _) => Ok(("accountId", account_id)),
+            (None, Some(name)) => Ok(("username", name)),
+            (None, None) => Err(Error::InvalidInput(
+                "the authenticated user has neither an account id nor a name".to_owned(),
+            )),
+        }
+    }
+
+    /// Create a copy of an issue
+    ///
+    /// Copies the summary, description, environment, labels and priority of the
+    /// source issue. Components are only copied when the clone stays in the
+    /// source project. Links and attachments are copied on request, which
+    /// takes one additional request per link and two per attachment.
+    ///
+    /// Fails with an [`Error::InvalidInput`] if the source issue has no
+    /// summary or issue type, or no project while `target_project` isn't set.
+    pub fn clone_issue<K>(&self, source_key: K, options: CloneOptions) -> Result<CreateResponse>
+    where
+        K: Into<String>,
+    {
+        // ask for the copied fields explicitly, default issue fields may leave them out
+        let source = self.get_with_options(
+            source_key,
+            &SearchOptions::builder()
+                .fields(vec![
+                    "project",
+                    "issuetype",
+                    "summary",
+                    "description",
+                    "environment",
+                    "labels",
+                    "priority",
+                    "components",
+                    "issuelinks",
+                    "attachment",
+                ])
+                .build(),
+        )?;
+        let missing =
+            |what: &str| Error::InvalidInput(format!("source issue {} has no {what}", source.key));
+        let source_project = source.project().map(|project| project.key);
+        let project = options
+            .target_project
+            .clone()
+            .or_else(|| source_project.clone())
+            .ok_or_else(|| missing("project"))?;
+        let summary = format!(
+            "{}{}",
+            options.summary_prefix.as_deref().unwrap_or_default(),
+            source.summary().ok_or_else(|| missing("summary"))?
+        );
+        let issue_type = source
+            .issue_type()
+            .map(|issue_type| issue_type.name)
+            .ok_or_else(|| missing("issue type"))?;
+
+        let mut builder = CreateIssueBuilder::new(project.clone(), issue_type, summary);
+        for name in ["description", "environment", "labels"] {
+            if let Some(value) = source.fields.get(name).filter(|value| !value.is_null()) {
+                builder.field(name, value);
+            }
+        }
+        if let Some(priority) = source.priority() {
+            builder.priority(priority.name);
+        }
+        if source_project.as_ref() == Some(&project) {
+            if let Some(Ok(components)) = source.field::<Vec<Component>>("components") {
+                builder.field(
+                    "components",
+                    components
+                        .into_iter()
+                        .map(|component| ::serde_json::json!({ "id": component.id }))
+                        .collect::<Vec<_>>(),
+                );
+            }
+        }
+
+        let created = self.create_from_custom_issue(builder.build()?)?;
+
+        if options.copy_links {
+            for link in source
+                .links()
+                .and_then(|links| links.ok())
+                .unwrap_or_default()
+            {
+                let (inward, outward) = match (link.inward_issue, link.outward_issue) {
+                    (_, Some(outward)) => (created.key.clone(), outward.key),
+                    (Some(inward), None) => (inward.key, created.key.clone()),
+                    (None, None) => continue,
+                };
+                self.jira.post::<EmptyResponse, _>(
+                    "api",
+                    "/issueLink",
+                    ::serde_json::json!({
+                        "type": { "name": link.link_type.name },
+                        "inwardIssue": { "key": inward },
+                        "outwardIssue": { "key": outward },
+                    }),
+                )?;
+            }
+        }
+
+        if options.copy_attachments {
+            let attachments = self.jira.attachments();
+            for attachment in source.attachment() {
+                let data = attachments.download(&attachment.content)?;
+                attachments.upload(created.key.clone(), attachment.filename, data)?;
+            }
+        }
+
+        Ok(created)
+    }
 }
 
 /// Provides an iterator over multiple pages of search results
 #[derive(Debug)]
 pub struct IssuesIter<'a> {
-    jira: Jira,
-    board: &'a Board,
-    results: IssueResults,
-    search_options: &'a SearchOptions,
+    pages: PageIter<BoardIssues<'a>>,
 }
 
 impl<'a> IssuesIter<'a> {
     fn new(board: &'a Board, options: &'a SearchOptions, jira: &Jira) -> Result<Self> {
-        let results = jira.issues().list(board, options)?;
-        Ok(IssuesIter {
-            board,
+        let fetcher = BoardIssues {
             jira: jira.clone(),
-            results,
+            board,
             search_options: options,
+        };
+        let results = fetcher.fetch_first()?;
+        Ok(IssuesIter {
+            pages: PageIter::new(results, fetcher),
         })
     }
-
-    fn more(&self) -> bool {
-        (self.results.start_at + self.results.max_results) <= self.results.total
-    }
 }
 
 impl<'a> Iterator for IssuesIter<'a> {
     type Item = Issue;
     fn next(&mut self) -> Option<Issue> {
-        self.results.issues.pop().or_else(|| {
-            if self.more() {
-                match self.jira.issues().list(
-                    self.board,
-                    &self
-                        .search_options
-                        .as_builder()
-                        .max_results(self.results.max_results)
-                        .start_at(self.results.start_at + self.results.max_results)
-                        .build(),
-                ) {
-                    Ok(new_results) => {
-                        self.results = new_results;
-                        self.results.issues.pop()
-                    }
-                    _ => None,
-                }
-            } else {
-                None
-            }
-        })
+        self.pages.next()
+    }
+}
+
+#[derive(Debug)]
+struct BoardIssues<'a> {
+    jira: Jira,
+    board: &'a Board,
+    search_options: &'a SearchOptions,
+}
+
+impl<'a> BoardIssues<'a> {
+    fn fetch_first(&self) -> Result<IssueResults> {
+        self.jira.issues().list(self.board, self.search_options)
+    }
+}
+
+impl<'a> PageFetcher for BoardIssues<'a> {
+    type Page = IssueResults;
+    fn fetch(&self, start_at: u64, max_results: u64) -> Result<IssueResults> {
+        self.jira.issues().list(
+            self.board,
+            &self
+                .search_options
+                .as_builder()
+                .max_results(max_results)
+                .start_at(start_at)
+                .build(),
+        )
+    }
+}
+
+/// A page of `/issue/{key}/changelog`
+#[derive(Deserialize, Debug)]
+struct ChangelogPage {
+    #[serde(rename = "startAt", default)]
+    start_at: u64,
+    #[serde(rename = "maxResults", default)]
+    max_results: u64,
+    total: Option<u64>,
+    #[serde(rename = "isLast")]
+    is_last: Option<bool>,
+    values: Vec<History>,
+}
+
+impl Paginated for ChangelogPage {
+    type Item = History;
+
+    fn start_at(&self) -> u64 {
+        self.start_at
+    }
+
+    fn max_results(&self) -> u64 {
+        self.max_results
+    }
+
+    fn total(&self) -> Option<u64> {
+        self.total
+    }
+
+    fn is_last(&self) -> Option<bool> {
+        self.is_last
+    }
+
+    fn items(&self) -> &[History] {
+        &self.values
+    }
+
+    fn into_items(self) -> Vec<History> {
+        self.values
+    }
+}
+
+impl Paginated for IssueResults {
+    type Item = Issue;
+
+    fn start_at(&self) -> u64 {
+        self.start_at
+    }
+
+    fn max_results(&self) -> u64 {
+        self.max_results
+    }
+
+    fn total(&self) -> Option<u64> {
+        Some(self.total)
+    }
+
+    fn items(&self) -> &[Issue] {
+        &self.issues
+    }
+
+    fn into_items(self) -> Vec<Issue> {
+        self.issues
     }
 }
@@ -0,0 +1,77 @@
+//! Parsing and serde helpers for the datetime formats used by Jira
+//!
+//! Jira formats timestamps like `2024-01-01T09:00:00.000+0000`, some
+//! endpoints use plain ISO 8601 and date fields only carry a date like
+//! `2024-01-01`. All of them are accepted; dates resolve to midnight UTC.
+//!
+//! Use with `#[serde(with = "gouqi::jira_datetime")]`, or
+//! `gouqi::jira_datetime::option` for optional fields.
+
+// Third party
+use serde::{de, Deserialize, Deserializer, Serializer};
+use time::{
+    format_description::{well_known::Iso8601, FormatItem},
+    macros::format_description,
+    Date, OffsetDateTime,
+};
+
+const JIRA_FORMAT: &[FormatItem<'static>] = format_description!(
+    "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond digits:3][offset_hour sign:mandatory][offset_minute]"
+);
+const DATE_FORMAT: &[FormatItem<'static>] = format_description!("[year]-[month]-[day]");
+
+/// Parses a Jira timestamp, an ISO 8601 timestamp or a plain date
+pub fn parse(value: &str) -> Result<OffsetDateTime, time::error::Parse> {
+    OffsetDateTime::parse(value, &JIRA_FORMAT)
+        .or_else(|_| OffsetDateTime::parse(value, &Iso8601::DEFAULT))
+        .or_else(|error| match Date::parse(value, &DATE_FORMAT) {
+            Ok(date) => Ok(date.midnight().assume_utc()),
+            Err(_) => Err(error),
+        })
+}
+
+/// Formats a timestamp the way Jira does, e.g. `2024-01-01T09:00:00.000+0000`
+pub fn format(value: &OffsetDateTime) -> String {
+    value
+        .format(&JIRA_FORMAT)
+        .expect("Timestamp to format with a static description")
+}
+
+pub fn serialize<S>(value: &OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&format(value))
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<OffsetDateTime, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    parse(&value).map_err(de::Error::custom)
+}
+
+/// Same as the parent module, for optional fields
+pub mod option {
+    use super::*;
+
+    pub fn serialize<S>(value: &Option<OffsetDateTime>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match value {
+            Some(value) => super::serialize(value, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<OffsetDateTime>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<String>::deserialize(deserializer)?
+            .map(|value| parse(&value).map_err(de::Error::custom))
+            .transpose()
+    }
+}
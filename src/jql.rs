@@ -0,0 +1,134 @@
+//! Helpers for building JQL clauses
+
+// Third party
+use time::{macros::format_description, Duration, OffsetDateTime, UtcOffset};
+
+// Ours
+use crate::{Error, Result};
+
+/// Formats a duration as a relative JQL date like `-7d`, `-4h` or `-30m`
+///
+/// The largest unit that divides the duration exactly is used. Durations
+/// smaller than a minute are rounded down to whole minutes.
+pub fn relative(duration: Duration) -> String {
+    let minutes = duration.whole_minutes().abs();
+    let value = if minutes != 0 && minutes % (60 * 24 * 7) == 0 {
+        format!("{}w", minutes / (60 * 24 * 7))
+    } else if minutes != 0 && minutes % (60 * 24) == 0 {
+        format!("{}d", minutes / (60 * 24))
+    } else if minutes != 0 && minutes % 60 == 0 {
+        format!("{}h", minutes / 60)
+    } else {
+        format!("{minutes}m")
+    };
+    if duration.is_negative() {
+        format!("+{value}")
+    } else {
+        format!("-{value}")
+    }
+}
+
+/// Formats a timestamp as a quoted absolute JQL date like `"2024/01/31 09:00"`
+///
+/// JQL dates carry no offset, Jira reads them in the timezone of the
+/// querying user. The wall clock time of `datetime` is formatted as-is, so
+/// it must already be in the user's offset: `2024-01-31 09:00 +02:00`
+/// becomes `"2024/01/31 09:00"` and is queried as 09:00 in the user's
+/// timezone. Use
+/// [`absolute_in`] to convert a timestamp in another offset first. The same
+/// holds for the clause helpers like [`created_after`].
+pub fn absolute(datetime: OffsetDateTime) -> String {
+    let format = format_description!("[year]/[month]/[day] [hour]:[minute]");
+    format!(
+        "\"{}\"",
+        datetime
+            .format(&format)
+            .expect("Date to format with a static description")
+    )
+}
+
+/// Formats a timestamp as a quoted absolute JQL date after converting it to
+/// the offset of the querying user's timezone
+pub fn absolute_in(datetime: OffsetDateTime, user_offset: UtcOffset) -> String {
+    absolute(datetime.to_offset(user_offset))
+}
+
+/// Formats a call to the `startOfDay()` JQL function, shifted by a number of days
+pub fn start_of_day(offset_days: i64) -> String {
+    match offset_days {
+        0 => "startOfDay()".to_owned(),
+        d => format!("startOfDay({d:+}d)"),
+    }
+}
+
+/// Matches issues updated within the given duration, e.g. `updated >= -7d`
+pub fn updated_within(duration: Duration) -> String {
+    format!("updated >= {}", relative(duration))
+}
+
+/// Matches issues created within the given duration, e.g. `created >= -7d`
+pub fn created_within(duration: Duration) -> String {
+    format!("created >= {}", relative(duration))
+}
+
+/// Matches issues created after the given timestamp
+pub fn created_after(datetime: OffsetDateTime) -> String {
+    format!("created > {}", absolute(datetime))
+}
+
+/// Matches issues created before the given timestamp
+pub fn created_before(datetime: OffsetDateTime) -> String {
+    format!("created < {}", absolute(datetime))
+}
+
+/// Matches issues updated after the given timestamp
+pub fn updated_after(datetime: OffsetDateTime) -> String {
+    format!("updated > {}", absolute(datetime))
+}
+
+/// Matches issues updated before the given timestamp
+pub fn updated_before(datetime: OffsetDateTime) -> String {
+    format!("updated < {}", absolute(datetime))
+}
+
+/// Checks a query for structural mistakes before it is sent
+///
+/// Rejects control characters other than whitespace, unterminated string
+/// literals and unbalanced parentheses. This catches queries which were
+/// broken by interpolating user input, it doesn't validate fields,
+/// operators or functions.
+pub fn validate(jql: &str) -> Result<()> {
+    let invalid = |message: String| Err(Error::InvalidInput(format!("invalid JQL: {message}")));
+
+    let mut open_parens = vec![];
+    let mut quote: Option<(char, usize)> = None;
+    let mut escaped = false;
+    for (position, c) in jql.char_indices() {
+        if c.is_control() && !matches!(c, '\t' | '\n' | '\r') {
+            return invalid(format!("control character {c:?} at position {position}"));
+        }
+        match quote {
+            Some(_) if escaped => escaped = false,
+            Some(_) if c == '\\' => escaped = true,
+            Some((open, _)) if c == open => quote = None,
+            Some(_) => (),
+            None => match c {
+                '"' | '\'' => quote = Some((c, position)),
+                '(' => open_parens.push(position),
+                ')' if open_parens.pop().is_none() => {
+                    return invalid(format!("unmatched ')' at position {position}"))
+                }
+                _ => (),
+            },
+        }
+    }
+    if let Some((open, position)) = quote {
+        return invalid(format!(
+            "unterminated string {open}...{open} starting at position {position}"
+        ));
+    }
+    if let Some(position) = open_parens.pop() {
+        return invalid(format!("unclosed '(' at position {position}"));
+    }
+    Ok(())
+}
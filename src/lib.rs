@@ -6,23 +6,33 @@ extern crate serde_derive;
 extern crate serde_json;
 extern crate url;
 
+use std::collections::HashMap;
 use std::io::Read;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Instant;
 use tracing::debug;
 
-use reqwest::header::CONTENT_TYPE;
+use reqwest::header::{HeaderMap, CONTENT_TYPE, COOKIE};
 use reqwest::{
-    blocking::{Client, RequestBuilder},
+    blocking::{multipart::Form, Client, RequestBuilder, Response},
     Method, StatusCode,
 };
 use serde::de::DeserializeOwned;
 use serde::Serialize;
-use url::Url;
+use url::{form_urlencoded, Url};
 
 pub mod attachments;
 mod builder;
 pub mod components;
+mod env;
 mod errors;
 pub mod issues;
+pub mod jira_datetime;
+pub mod jql;
+mod metadata;
+mod middleware;
+mod pagination;
+pub mod projects;
 mod rep;
 mod search;
 mod transitions;
@@ -33,8 +43,14 @@ pub use crate::builder::*;
 pub use crate::components::*;
 pub use crate::errors::*;
 pub use crate::issues::*;
+pub use crate::metadata::MetadataCache;
+pub use crate::middleware::RequestMiddleware;
+pub use crate::pagination::*;
+pub use crate::projects::*;
 pub use crate::rep::*;
 pub use crate::search::Search;
+#[cfg(feature = "streaming")]
+pub use crate::search::SearchPage;
 pub use crate::transitions::*;
 pub mod boards;
 pub mod resolution;
@@ -46,22 +62,54 @@ pub use crate::versions::*;
 #[derive(Deserialize, Debug)]
 pub struct EmptyResponse;
 
+/// Outcome of [`Jira::health_check`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthStatus {
+    /// Whether the server answered at all
+    pub reachable: bool,
+    /// Whether the configured credentials were accepted, always false for anonymous access
+    pub authenticated: bool,
+    /// Round trip time of the server info request
+    pub latency_ms: u64,
+    /// Deployment type reported by the server, e.g. `Cloud` or `Server`
+    pub deployment_type: Option<String>,
+}
+
+/// Parses the host url, making sure any context path ends with a slash so
+/// that relative joins stay below it
+fn parse_host(host: String) -> Result<Url> {
+    let mut url = Url::parse(&host)?;
+    if !url.path().ends_with('/') {
+        let path = format!("{}/", url.path());
+        url.set_path(&path);
+    }
+    Ok(url)
+}
+
 pub type Result<T> = std::result::Result<T, Error>;
 
 /// Types of authentication credentials
 ///
 /// # Notes
 ///
-/// - Personal Access Token are used with [`Credentials::Basic`] scheme as a password replacement and *not* as a [`Credentials::Bearer`]
-///   like the [API documentation sugest](https://developer.atlassian.com/server/jira/platform/rest-apis/#authentication-and-authorization).
+/// - On Jira Cloud, use [`Credentials::Basic`] with the account email and an
+///   [API token](https://support.atlassian.com/atlassian-account/docs/manage-api-tokens-for-your-atlassian-account/)
+///   as the password.
+/// - On Jira Server and Data Center, use [`Credentials::PersonalAccessToken`] with a
+///   [Personal Access Token](https://confluence.atlassian.com/enterprise/using-personal-access-tokens-1026032365.html),
+///   which is sent as a bearer token.
 #[derive(Clone, Debug)]
 pub enum Credentials {
     /// Use no authentication
     Anonymous,
-    /// Username and password credentials (Personal Access Token count as a password)
+    /// Username and password credentials, or email and API token on Jira Cloud
     Basic(String, String),
     /// Authentification via bearer token
     Bearer(String),
+    /// Personal Access Token of Jira Server and Data Center, sent as a bearer token
+    PersonalAccessToken(String),
+    /// Session cookie(s) sent verbatim in the `Cookie` header, e.g. `JSESSIONID=...`
+    Cookie(String),
     // TODO: Add OAuth
 }
 
@@ -72,18 +120,44 @@ impl Credentials {
             Credentials::Basic(ref user, ref pass) => {
                 request.basic_auth(user.to_owned(), Some(pass.to_owned()))
             }
-            Credentials::Bearer(ref token) => request.bearer_auth(token.to_owned()),
+            Credentials::Bearer(ref token) | Credentials::PersonalAccessToken(ref token) => {
+                request.bearer_auth(token.to_owned())
+            }
+            Credentials::Cookie(ref cookie) => request.header(COOKIE, cookie.to_owned()),
         }
     }
 }
 
 /// Entrypoint into client interface
 /// <https://docs.atlassian.com/jira/REST/latest/>
+///
+/// Cloning is cheap: the configuration and the connection pool are shared
+/// between copies, so interfaces like [`Jira::issues`] can be created freely.
+/// Changing the configuration of a copy, e.g. with [`Jira::set_read_only`],
+/// leaves the other copies untouched.
 #[derive(Clone, Debug)]
 pub struct Jira {
+    core: Arc<ClientCore>,
+    /// The authenticated user, shared by all copies of the client once resolved
+    myself: Arc<OnceLock<User>>,
+    /// Createmeta by project and issue type query, see [`Issues::create_validated`]
+    create_meta: Arc<Mutex<HashMap<String, CreateMetaIssueType>>>,
+    /// Statuses, priorities and resolutions, see [`Jira::metadata_cache`]
+    metadata: Arc<OnceLock<metadata::Metadata>>,
+}
+
+/// Configuration of a [`Jira`] client, copied only when a shared one changes
+#[derive(Clone, Debug)]
+struct ClientCore {
     host: Url,
     credentials: Credentials,
     client: Client,
+    read_only: bool,
+    /// REST roots replacing `rest/{api_name}/latest`, by api name
+    api_paths: HashMap<String, String>,
+    /// Fields returned for issues unless the options of a call choose others
+    default_issue_fields: Option<Vec<String>>,
+    middlewares: middleware::Middlewares,
 }
 
 impl Jira {
@@ -92,31 +166,141 @@ impl Jira {
     where
         H: Into<String>,
     {
-        match Url::parse(&host.into()) {
-            Ok(host) => Ok(Jira {
-                host,
-                client: Client::new(),
-                credentials,
-            }),
-            Err(error) => Err(Error::from(error)),
-        }
+        Jira::builder(host).credentials(credentials).build()
+    }
+
+    /// Return a new instance of a builder for a configured client
+    pub fn builder<H>(host: H) -> JiraBuilder
+    where
+        H: Into<String>,
+    {
+        JiraBuilder::new(host)
+    }
+
+    /// Creates a new instance of a jira client from the environment
+    ///
+    /// The host is read from `JIRA_URL`, falling back to `JIRA_HOST`. Exactly one
+    /// set of credentials must be present:
+    ///
+    /// - `JIRA_USER` and `JIRA_TOKEN` for [`Credentials::Basic`]
+    /// - `JIRA_PAT` for [`Credentials::PersonalAccessToken`]
+    /// - `JIRA_COOKIE` for [`Credentials::Cookie`]
+    ///
+    /// Missing, incomplete or conflicting variables result in an [`Error::InvalidInput`].
+    pub fn from_env() -> Result<Jira> {
+        let (host, credentials) = env::from_env()?;
+        Jira::new(host, credentials)
     }
 
     /// Creates a new instance of a jira client using a specified reqwest client
+    ///
+    /// Use this to share a connection pool or centrally configured TLS, proxy
+    /// or default header settings. See [`JiraBuilder::http_client`] to combine
+    /// it with the other client options.
     pub fn from_client<H>(host: H, credentials: Credentials, client: Client) -> Result<Jira>
     where
         H: Into<String>,
     {
-        match Url::parse(&host.into()) {
-            Ok(host) => Ok(Jira {
-                host,
+        Ok(Jira {
+            core: Arc::new(ClientCore {
+                host: parse_host(host.into())?,
                 client,
                 credentials,
+                read_only: false,
+                api_paths: HashMap::new(),
+                default_issue_fields: None,
+                middlewares: middleware::Middlewares::default(),
             }),
-            Err(error) => Err(Error::from(error)),
+            myself: Arc::default(),
+            create_meta: Arc::default(),
+            metadata: Arc::default(),
+        })
+    }
+
+    /// Sets the context path Jira is served under, e.g. `/jira` when Jira is
+    /// reachable at `https://host/jira`. REST urls and permalinks are resolved
+    /// below this path.
+    ///
+    /// A context path can also be given as part of the host in [`Jira::new`].
+    pub fn with_base_path(mut self, base_path: &str) -> Jira {
+        let base_path = base_path.trim_matches('/');
+        let host = &mut Arc::make_mut(&mut self.core).host;
+        if base_path.is_empty() {
+            host.set_path("/");
+        } else {
+            host.set_path(&format!("/{base_path}/"));
+        }
+        self
+    }
+
+    /// Replaces the REST root of an api, e.g. `/custom/rest/api/2` for `api`
+    /// instead of `/rest/api/latest`, for gateways which relocate it
+    ///
+    /// The path is resolved below the host and base path, endpoints are
+    /// appended to it. Apis without an override keep the default root.
+    pub fn with_base_api_path<A, P>(mut self, api_name: A, path: P) -> Jira
+    where
+        A: Into<String>,
+        P: Into<String>,
+    {
+        let path = path.into().trim_matches('/').to_owned();
+        Arc::make_mut(&mut self.core)
+            .api_paths
+            .insert(api_name.into(), path);
+        self
+    }
+
+    /// Restricts the fields returned for issues by default, e.g. to `key`,
+    /// `summary` and `status`, to keep payloads small
+    ///
+    /// Applies to issue gets, searches and board or sprint issue listings
+    /// whose options don't set fields themselves. Set fields explicitly, e.g.
+    /// to `*all`, to override the default for a single call.
+    pub fn with_default_issue_fields<F>(mut self, fields: Vec<F>) -> Jira
+    where
+        F: Into<String>,
+    {
+        Arc::make_mut(&mut self.core).default_issue_fields =
+            Some(fields.into_iter().map(|f| f.into()).collect());
+        self
+    }
+
+    /// Applies the default issue fields to the options of a call
+    pub(crate) fn issue_options(&self, options: &SearchOptions) -> SearchOptions {
+        match self.core.default_issue_fields {
+            Some(ref fields) => options.or_fields(fields),
+            None => options.clone(),
+        }
+    }
+
+    /// Registers middleware invoked around every request, see [`RequestMiddleware`]
+    pub fn with_middleware<M>(mut self, middleware: M) -> Jira
+    where
+        M: RequestMiddleware + 'static,
+    {
+        Arc::make_mut(&mut self.core)
+            .middlewares
+            .push(Arc::new(middleware));
+        self
+    }
+
+    /// Blocks every request which could modify data
+    ///
+    /// While set, POST, PUT and DELETE requests fail with an
+    /// [`Error::InvalidInput`] before anything is sent, and only GET requests
+    /// reach the server. Interfaces like [`Jira::issues`] copy the client, so
+    /// the mode applies to interfaces obtained after setting it.
+    pub fn set_read_only(&mut self, read_only: bool) {
+        if self.core.read_only != read_only {
+            Arc::make_mut(&mut self.core).read_only = read_only;
         }
     }
 
+    /// Whether the client refuses requests which could modify data
+    pub fn is_read_only(&self) -> bool {
+        self.core.read_only
+    }
+
     /// Return transitions interface
     pub fn transitions<K>(&self, key: K) -> Transitions
     where
@@ -147,6 +331,23 @@ impl Jira {
         Components::new(self)
     }
 
+    // Return metadata cache interface
+    pub fn metadata_cache(&self) -> MetadataCache {
+        MetadataCache::new(self)
+    }
+
+    // Return resolutions interface
+    #[tracing::instrument]
+    pub fn resolutions(&self) -> resolution::Resolutions {
+        resolution::Resolutions::new(self)
+    }
+
+    // Return projects interface
+    #[tracing::instrument]
+    pub fn projects(&self) -> Projects {
+        Projects::new(self)
+    }
+
     // Return boards interface
     #[tracing::instrument]
     pub fn boards(&self) -> Boards {
@@ -168,6 +369,108 @@ impl Jira {
         self.get("auth", "/session")
     }
 
+    /// Returns the currently authenticated user
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/myself-getUser)
+    /// for more information
+    pub fn myself(&self) -> Result<User> {
+        self.get("api", "/myself")
+    }
+
+    /// Returns the currently authenticated user, requesting it only once
+    pub(crate) fn cached_myself(&self) -> Result<User> {
+        if let Some(user) = self.myself.get() {
+            return Ok(user.clone());
+        }
+        let user = self.myself()?;
+        Ok(self.myself.get_or_init(|| user).clone())
+    }
+
+    /// Returns general information about the Jira instance
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/serverInfo-getServerInfo)
+    /// for more information
+    pub fn server_info(&self) -> Result<ServerInfo> {
+        self.get("api", "/serverInfo")
+    }
+
+    /// Checks that the host is reachable and the credentials are accepted
+    ///
+    /// Queries the server info, which does not require authentication, and
+    /// then the current user unless the credentials are anonymous. Failing to
+    /// reach the server or being rejected is reported in the returned status,
+    /// other failures are returned as errors.
+    pub fn health_check(&self) -> Result<HealthStatus> {
+        let started = Instant::now();
+        let server_info = self.server_info();
+        let mut status = HealthStatus {
+            reachable: true,
+            authenticated: false,
+            latency_ms: started.elapsed().as_millis() as u64,
+            deployment_type: None,
+        };
+        match server_info {
+            Ok(info) => status.deployment_type = info.deployment_type,
+            Err(Error::Http(_)) => {
+                status.reachable = false;
+                return Ok(status);
+            }
+            Err(_) => {}
+        }
+
+        if let Credentials::Anonymous = self.core.credentials {
+            return Ok(status);
+        }
+        match self.myself() {
+            Ok(_) => status.authenticated = true,
+            Err(Error::Unauthorized) => {}
+            Err(Error::Fault { code, .. }) if code == StatusCode::FORBIDDEN => {}
+            Err(Error::Http(_)) => status.reachable = false,
+            Err(error) => return Err(error),
+        }
+        Ok(status)
+    }
+
+    /// Returns which of the given permissions the current user has, optionally
+    /// in the context of a project or issue.
+    ///
+    /// Jira Cloud requires the permissions to be listed, while Jira Server
+    /// returns all permissions when `permissions` is empty.
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/mypermissions-getPermissions)
+    /// for more information
+    pub fn my_permissions(
+        &self,
+        project_key: Option<String>,
+        issue_key: Option<String>,
+        permissions: &[&str],
+    ) -> Result<HashMap<String, bool>> {
+        let mut query = form_urlencoded::Serializer::new(String::new());
+        if let Some(project_key) = project_key {
+            query.append_pair("projectKey", &project_key);
+        }
+        if let Some(issue_key) = issue_key {
+            query.append_pair("issueKey", &issue_key);
+        }
+        if !permissions.is_empty() {
+            query.append_pair("permissions", &permissions.join(","));
+        }
+        let query = query.finish();
+
+        let path = if query.is_empty() {
+            "/mypermissions".to_owned()
+        } else {
+            format!("/mypermissions?{query}")
+        };
+        self.get::<MyPermissions>("api", &path).map(|response| {
+            response
+                .permissions
+                .into_iter()
+                .map(|(key, permission)| (key, permission.have_permission))
+                .collect()
+        })
+    }
+
     /// Sends a DELETE request using the Jira client.
     ///
     /// # Arguments
@@ -294,7 +597,82 @@ impl Jira {
         self.request::<D>(Method::PUT, api_name, endpoint, Some(data.into_bytes()))
     }
 
-    #[tracing::instrument]
+    /// Sends a multipart POST request using the Jira client.
+    ///
+    /// Jira requires the `X-Atlassian-Token: no-check` header on multipart
+    /// uploads, which is added automatically.
+    pub(crate) fn post_multipart<D>(&self, api_name: &str, endpoint: &str, form: Form) -> Result<D>
+    where
+        D: DeserializeOwned,
+    {
+        self.ensure_allowed(&Method::POST)?;
+        let req = self
+            .core
+            .client
+            .post(self.url(api_name, endpoint)?)
+            .header("X-Atlassian-Token", "no-check")
+            .multipart(form);
+
+        let body = self.send(req)?;
+        let data: &[u8] = if body.is_empty() { b"null" } else { &body };
+        Ok(serde_json::from_slice::<D>(data)?)
+    }
+
+    /// Downloads raw content from an absolute url, e.g. the content of an attachment
+    ///
+    /// The request carries the client's credentials, so urls pointing
+    /// anywhere but the Jira host are refused.
+    pub(crate) fn download(&self, url: &str) -> Result<Vec<u8>> {
+        let url = Url::parse(url)?;
+        if url.origin() != self.core.host.origin() {
+            return Err(Error::InvalidInput(format!(
+                "refusing to download {url} from outside of the Jira host"
+            )));
+        }
+        self.send(self.core.client.get(url))
+    }
+
+    /// Sends a request with an arbitrary method and additional headers.
+    ///
+    /// This is an advanced escape hatch for calls the typed interfaces and the
+    /// `get`/`post`/`put`/`delete` helpers don't cover, e.g. a PATCH. Credentials,
+    /// the base url, the read-only mode and error mapping apply as usual. Its
+    /// signature may change between releases.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - HTTP method of the request
+    /// * `api_name` - Name of the API: like "agile" or "api"
+    /// * `endpoint` - API endpoint path
+    /// * `headers` - Headers added to the request, replacing defaults like `Content-Type`
+    /// * `body` - JSON body of the request, if any
+    ///
+    /// # Returns
+    ///
+    /// `Result<Value>` - The JSON response, `Value::Null` for empty responses
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use gouqi::Credentials;
+    /// # use gouqi::Jira;
+    /// # use reqwest::{header::HeaderMap, Method};
+    /// # let jira = Jira::new("http://localhost".to_string(), Credentials::Anonymous).unwrap();
+    /// let body = serde_json::json!({ "name": "Renamed" });
+    /// let response = jira.raw_request(Method::PATCH, "api", "/endpoint", HeaderMap::new(), Some(body));
+    /// ```
+    pub fn raw_request(
+        &self,
+        method: Method,
+        api_name: &str,
+        endpoint: &str,
+        headers: HeaderMap,
+        body: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value> {
+        let body = body.map(|body| serde_json::to_vec(&body)).transpose()?;
+        self.request_with_headers(method, api_name, endpoint, headers, body)
+    }
+
     fn request<D>(
         &self,
         method: Method,
@@ -305,40 +683,114 @@ impl Jira {
     where
         D: DeserializeOwned,
     {
-        let url = self
-            .host
-            .join(&format!("rest/{api_name}/latest{endpoint}"))?;
-        debug!("url -> {:?}", url);
+        self.request_with_headers(method, api_name, endpoint, HeaderMap::new(), body)
+    }
 
+    #[tracing::instrument]
+    fn request_with_headers<D>(
+        &self,
+        method: Method,
+        api_name: &str,
+        endpoint: &str,
+        headers: HeaderMap,
+        body: Option<Vec<u8>>,
+    ) -> Result<D>
+    where
+        D: DeserializeOwned,
+    {
+        self.ensure_allowed(&method)?;
         let mut req = self
+            .core
             .client
-            .request(method, url)
-            .header(CONTENT_TYPE, "application/json");
-
-        req = self.credentials.apply(req);
+            .request(method, self.url(api_name, endpoint)?)
+            .header(CONTENT_TYPE, "application/json")
+            .headers(headers);
 
         if let Some(body) = body {
             req = req.body(body);
         }
+
+        let body = self.send(req)?;
+        let data: &[u8] = if body.is_empty() { b"null" } else { &body };
+        Ok(serde_json::from_slice::<D>(data)?)
+    }
+
+    /// Fails for requests which may modify data on a read-only client
+    fn ensure_allowed(&self, method: &Method) -> Result<()> {
+        if self.core.read_only && method != Method::GET {
+            return Err(Error::InvalidInput("client is read-only".to_owned()));
+        }
+        Ok(())
+    }
+
+    fn url(&self, api_name: &str, endpoint: &str) -> Result<Url> {
+        let url = match self.core.api_paths.get(api_name) {
+            Some(path) => self.core.host.join(&format!("{path}{endpoint}"))?,
+            None => self
+                .core
+                .host
+                .join(&format!("rest/{api_name}/latest{endpoint}"))?,
+        };
+        debug!("url -> {:?}", url);
+        Ok(url)
+    }
+
+    /// Applies the credentials, sends the request and returns the raw body of
+    /// a successful response
+    fn send(&self, req: RequestBuilder) -> Result<Vec<u8>> {
+        let mut res = self.send_unread(req)?;
+
+        let mut body = Vec::new();
+        res.read_to_end(&mut body)?;
+        debug!(
+            "status {:?} body '{:?}'",
+            res.status(),
+            String::from_utf8_lossy(&body)
+        );
+        Ok(body)
+    }
+
+    /// Applies the credentials and sends the request, returning a successful
+    /// response without reading its body
+    fn send_unread(&self, req: RequestBuilder) -> Result<Response> {
+        let mut req = self.core.credentials.apply(req).build()?;
+        self.core.middlewares.before_send(&mut req);
         debug!("req '{:?}'", req);
 
-        let mut res = req.send()?;
+        let mut res = self.core.client.execute(req)?;
+        self.core.middlewares.after_receive(&res);
+        let code = res.status();
+        if !(code.is_client_error() || code.is_server_error()) {
+            return Ok(res);
+        }
 
-        let mut body = String::new();
-        res.read_to_string(&mut body)?;
-        debug!("status {:?} body '{:?}'", res.status(), body);
-        match res.status() {
+        let mut body = Vec::new();
+        res.read_to_end(&mut body)?;
+        debug!(
+            "status {:?} body '{:?}'",
+            code,
+            String::from_utf8_lossy(&body)
+        );
+        match code {
             StatusCode::UNAUTHORIZED => Err(Error::Unauthorized),
             StatusCode::METHOD_NOT_ALLOWED => Err(Error::MethodNotAllowed),
             StatusCode::NOT_FOUND => Err(Error::NotFound),
-            client_err if client_err.is_client_error() => Err(Error::Fault {
-                code: res.status(),
-                errors: serde_json::from_str::<Errors>(&body)?,
+            code => Err(Error::Fault {
+                code,
+                errors: Errors::from_body(&body),
             }),
-            _ => {
-                let data = if body.is_empty() { "null" } else { &body };
-                Ok(serde_json::from_str::<D>(data)?)
-            }
         }
     }
+
+    /// Sends a GET request and returns the successful response unread, for
+    /// deserializing large bodies incrementally
+    #[cfg(feature = "streaming")]
+    pub(crate) fn get_unread(&self, api_name: &str, endpoint: &str) -> Result<Response> {
+        let req = self
+            .core
+            .client
+            .request(Method::GET, self.url(api_name, endpoint)?)
+            .header(CONTENT_TYPE, "application/json");
+        self.send_unread(req)
+    }
 }
@@ -0,0 +1,88 @@
+//! Statuses, priorities and resolutions, fetched once and looked up by id
+
+// Third party
+use std::collections::HashMap;
+
+// Ours
+use crate::{Jira, Priority, Resolution, Result, Status};
+
+/// Metadata shared by all copies of a client once warmed up
+#[derive(Debug)]
+pub(crate) struct Metadata {
+    statuses: HashMap<String, Status>,
+    priorities: HashMap<String, Priority>,
+    resolutions: HashMap<String, Resolution>,
+}
+
+/// Metadata cache interface
+///
+/// Resolving the names of statuses, priorities and resolutions from their
+/// ids, e.g. when rendering many issues, only requests them from the server
+/// once. The cache is shared by all copies of the client and kept for their
+/// lifetime.
+#[derive(Debug)]
+pub struct MetadataCache {
+    jira: Jira,
+}
+
+impl MetadataCache {
+    pub fn new(jira: &Jira) -> MetadataCache {
+        MetadataCache { jira: jira.clone() }
+    }
+
+    /// Fetches the statuses, priorities and resolutions unless already cached
+    ///
+    /// Lookups warm up the cache on their own, calling this up front moves
+    /// the requests out of e.g. a rendering loop.
+    pub fn warm_up(&self) -> Result<()> {
+        self.metadata().map(|_| ())
+    }
+
+    /// Looks up a status by id
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/status-getStatuses)
+    /// for more information
+    pub fn lookup_status(&self, id: &str) -> Result<Option<Status>> {
+        Ok(self.metadata()?.statuses.get(id).cloned())
+    }
+
+    /// Looks up a priority by id
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/priority-getPriorities)
+    /// for more information
+    pub fn lookup_priority(&self, id: &str) -> Result<Option<Priority>> {
+        Ok(self.metadata()?.priorities.get(id).cloned())
+    }
+
+    /// Looks up a resolution by id
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/resolution-getResolutions)
+    /// for more information
+    pub fn lookup_resolution(&self, id: &str) -> Result<Option<Resolution>> {
+        Ok(self.metadata()?.resolutions.get(id).cloned())
+    }
+
+    fn metadata(&self) -> Result<&Metadata> {
+        if let Some(metadata) = self.jira.metadata.get() {
+            return Ok(metadata);
+        }
+        let statuses = self.jira.get::<Vec<Status>>("api", "/status")?;
+        let priorities = self.jira.get::<Vec<Priority>>("api", "/priority")?;
+        let resolutions = self.jira.resolutions().list()?;
+        let metadata = Metadata {
+            statuses: statuses
+                .into_iter()
+                .map(|status| (status.id.clone(), status))
+                .collect(),
+            priorities: priorities
+                .into_iter()
+                .map(|priority| (priority.id.clone(), priority))
+                .collect(),
+            resolutions: resolutions
+                .into_iter()
+                .filter_map(|resolution| Some((resolution.id.clone()?, resolution)))
+                .collect(),
+        };
+        Ok(self.jira.metadata.get_or_init(|| metadata))
+    }
+}
@@ -0,0 +1,59 @@
+//! Hooks into the requests a client sends
+
+// Third party
+use reqwest::blocking::{Request, Response};
+use std::fmt;
+use std::sync::Arc;
+
+/// Hooks invoked around every request a [`Jira`](crate::Jira) client sends,
+/// e.g. to sign requests, add headers or audit responses
+///
+/// Middleware is registered with [`Jira::with_middleware`](crate::Jira::with_middleware)
+/// and runs in registration order, after the credentials were applied.
+pub trait RequestMiddleware: Send + Sync {
+    /// Called with the complete request right before it is sent
+    fn before_send(&self, _req: &mut Request) {}
+
+    /// Called with every response, including error responses, before its
+    /// body is read
+    fn after_receive(&self, _resp: &Response) {}
+}
+
+/// Shared middleware, e.g. to inspect state it collected after registering it
+impl<T: RequestMiddleware + ?Sized> RequestMiddleware for Arc<T> {
+    fn before_send(&self, req: &mut Request) {
+        (**self).before_send(req)
+    }
+
+    fn after_receive(&self, resp: &Response) {
+        (**self).after_receive(resp)
+    }
+}
+
+/// Registered middleware of a client
+#[derive(Clone, Default)]
+pub(crate) struct Middlewares(Vec<Arc<dyn RequestMiddleware>>);
+
+impl Middlewares {
+    pub(crate) fn push(&mut self, middleware: Arc<dyn RequestMiddleware>) {
+        self.0.push(middleware);
+    }
+
+    pub(crate) fn before_send(&self, req: &mut Request) {
+        for middleware in &self.0 {
+            middleware.before_send(req);
+        }
+    }
+
+    pub(crate) fn after_receive(&self, resp: &Response) {
+        for middleware in &self.0 {
+            middleware.after_receive(resp);
+        }
+    }
+}
+
+impl fmt::Debug for Middlewares {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Middlewares({})", self.0.len())
+    }
+}
@@ -0,0 +1,122 @@
+//! Shared pagination over offset based Jira endpoints
+
+// Third party
+use std::fmt;
+
+// Ours
+use crate::Result;
+
+/// A single page of results from an endpoint paginated with `startAt`/`maxResults`
+pub trait Paginated {
+    type Item;
+
+    /// Offset of the first item of this page
+    fn start_at(&self) -> u64;
+
+    /// Page size the server used, which may be lower than the one requested
+    fn max_results(&self) -> u64;
+
+    /// Total number of items, for endpoints which report one
+    fn total(&self) -> Option<u64> {
+        None
+    }
+
+    /// Whether this is the last page, for endpoints which report it
+    fn is_last(&self) -> Option<bool> {
+        None
+    }
+
+    /// Items of this page
+    fn items(&self) -> &[Self::Item];
+
+    /// Consumes the page, returning its items
+    fn into_items(self) -> Vec<Self::Item>;
+
+    /// Whether another page follows this one
+    ///
+    /// The decision is based on the number of items actually returned rather
+    /// than the requested page size. An empty page always ends the iteration,
+    /// so a server reporting a stale `total` can't cause an endless loop.
+    fn has_more(&self) -> bool {
+        let returned = self.items().len() as u64;
+        if returned == 0 {
+            return false;
+        }
+        match (self.is_last(), self.total()) {
+            (Some(is_last), _) => !is_last,
+            (None, Some(total)) => self.start_at() + returned < total,
+            (None, None) => returned >= self.max_results(),
+        }
+    }
+}
+
+/// Fetches pages of an endpoint for a [`PageIter`]
+pub trait PageFetcher {
+    type Page: Paginated;
+
+    /// Fetches the page starting at `start_at` with up to `max_results` items
+    fn fetch(&self, start_at: u64, max_results: u64) -> Result<Self::Page>;
+}
+
+/// Iterates over the items of consecutive pages, fetching them as needed
+///
+/// Iteration stops at the first page which fails to load.
+pub struct PageIter<F: PageFetcher> {
+    fetcher: F,
+    items: std::vec::IntoIter<<F::Page as Paginated>::Item>,
+    next_start_at: Option<u64>,
+    max_results: u64,
+}
+
+impl<F: PageFetcher> PageIter<F> {
+    /// Starts iterating at an already fetched first page
+    pub fn new(first: F::Page, fetcher: F) -> PageIter<F> {
+        let mut iter = PageIter {
+            fetcher,
+            items: Vec::new().into_iter(),
+            next_start_at: None,
+            max_results: 0,
+        };
+        iter.load(first);
+        iter
+    }
+
+    fn load(&mut self, page: F::Page) {
+        self.next_start_at = if page.has_more() {
+            Some(page.start_at() + page.items().len() as u64)
+        } else {
+            None
+        };
+        self.max_results = page.max_results();
+        self.items = page.into_items().into_iter();
+    }
+}
+
+impl<F: PageFetcher + fmt::Debug> fmt::Debug for PageIter<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PageIter")
+            .field("fetcher", &self.fetcher)
+            .field("remaining", &self.items.len())
+            .field("next_start_at", &self.next_start_at)
+            .field("max_results", &self.max_results)
+            .finish()
+    }
+}
+
+impl<F: PageFetcher> Iterator for PageIter<F> {
+    type Item = <F::Page as Paginated>::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(item) = self.items.next() {
+            return Some(item);
+        }
+        let start_at = self.next_start_at.take()?;
+        match self.fetcher.fetch(start_at, self.max_results) {
+            Ok(page) => {
+                self.load(page);
+                self.items.next()
+            }
+            Err(_) => None,
+        }
+    }
+}
@@ -0,0 +1,335 @@
+//! Interfaces for accessing and managing projects
+
+// Third party
+use reqwest::Method;
+use url::form_urlencoded;
+
+// Ours
+use crate::{
+    EmptyResponse, Error, IssueType, Jira, PageFetcher, PageIter, Paginated, Project, Result,
+    SearchOptions,
+};
+
+#[derive(Debug)]
+pub struct Projects {
+    jira: Jira,
+}
+
+#[derive(Deserialize, Debug)]
+struct ProjectIssueTypes {
+    #[serde(default, rename = "issueTypes")]
+    issue_types: Vec<IssueType>,
+}
+
+/// A page of results of `Projects::search`
+#[derive(Deserialize, Debug)]
+pub struct ProjectSearchResults {
+    #[serde(rename = "maxResults")]
+    pub max_results: u64,
+    #[serde(rename = "startAt")]
+    pub start_at: u64,
+    pub total: u64,
+    #[serde(rename = "isLast")]
+    pub is_last: Option<bool>,
+    pub values: Vec<Project>,
+}
+
+/// Notification scheme of a project, see `Projects::notification_scheme`
+#[derive(Deserialize, Debug, Clone)]
+pub struct NotificationScheme {
+    #[serde(rename = "self")]
+    pub self_link: String,
+    pub id: u64,
+    pub name: String,
+    pub description: Option<String>,
+    /// Recipients notified by event
+    #[serde(default, rename = "notificationSchemeEvents")]
+    pub events: Vec<NotificationSchemeEvent>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct NotificationSchemeEvent {
+    pub event: NotificationEvent,
+    #[serde(default)]
+    pub notifications: Vec<EventNotification>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct NotificationEvent {
+    pub id: u64,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+/// A recipient of notifications, e.g. the `CurrentAssignee` or a `Group`
+#[derive(Deserialize, Debug, Clone)]
+pub struct EventNotification {
+    pub id: u64,
+    #[serde(rename = "notificationType")]
+    pub notification_type: String,
+    /// The group, user, field or role id for types which need one
+    pub parameter: Option<String>,
+}
+
+/// Issue security level of a project, restricting who may see an issue
+#[derive(Deserialize, Debug, Clone)]
+pub struct SecurityLevel {
+    #[serde(rename = "self")]
+    pub self_link: String,
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct SecurityLevels {
+    #[serde(default)]
+    levels: Vec<SecurityLevel>,
+}
+
+impl Projects {
+    pub fn new(jira: &Jira) -> Projects {
+        Projects { jira: jira.clone() }
+    }
+
+    /// Get a single project
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/project-getProject)
+    /// for more information
+    pub fn get<K>(&self, project_id_or_key: K) -> Result<Project>
+    where
+        K: Into<String>,
+    {
+        self.jira
+            .get("api", &format!("/project/{}", project_id_or_key.into()))
+    }
+
+    /// Returns the issue types available in a project, subtask types are
+    /// flagged with `IssueType::subtask`
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/project-getProject)
+    /// for more information
+    pub fn issue_types<K>(&self, project_id_or_key: K) -> Result<Vec<IssueType>>
+    where
+        K: Into<String>,
+    {
+        self.jira
+            .get::<ProjectIssueTypes>("api", &format!("/project/{}", project_id_or_key.into()))
+            .map(|project| project.issue_types)
+    }
+
+    /// Returns the notification scheme of a project
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/project-getNotificationScheme)
+    /// for more information
+    pub fn notification_scheme<K>(&self, project_id_or_key: K) -> Result<NotificationScheme>
+    where
+        K: Into<String>,
+    {
+        self.jira.get(
+            "api",
+            &format!("/project/{}/notificationscheme", project_id_or_key.into()),
+        )
+    }
+
+    /// Returns the issue security levels the current user may set in a project
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/project/{projectKeyOrId}/securitylevel-getSecurityLevelsForProject)
+    /// for more information
+    pub fn security_levels<K>(&self, project_id_or_key: K) -> Result<Vec<SecurityLevel>>
+    where
+        K: Into<String>,
+    {
+        self.jira
+            .get::<SecurityLevels>(
+                "api",
+                &format!("/project/{}/securitylevel", project_id_or_key.into()),
+            )
+            .map(|levels| levels.levels)
+    }
+
+    /// Returns all projects visible to the current user
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/project-getAllProjects)
+    /// for more information
+    pub fn list(&self) -> Result<Vec<Project>> {
+        self.jira.get("api", "/project")
+    }
+
+    /// Returns a single page of projects matching the options, e.g. a `query`
+    /// on the key or name
+    ///
+    /// See this [jira docs](https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-projects/#api-rest-api-2-project-search-get)
+    /// for more information
+    pub fn search(&self, options: &SearchOptions) -> Result<ProjectSearchResults> {
+        let mut path = vec!["/project/search".to_owned()];
+        let query_options = options.serialize().unwrap_or_default();
+        let query = form_urlencoded::Serializer::new(query_options).finish();
+
+        path.push(query);
+
+        self.jira
+            .get::<ProjectSearchResults>("api", path.join("?").as_ref())
+    }
+
+    /// Returns a type which may be used to iterate over consecutive pages of results
+    ///
+    /// See this [jira docs](https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-projects/#api-rest-api-2-project-search-get)
+    /// for more information
+    pub fn search_iter<'a>(&self, options: &'a SearchOptions) -> Result<ProjectsIter<'a>> {
+        ProjectsIter::new(options, &self.jira)
+    }
+
+    /// Archive a project
+    ///
+    /// Archiving is only available on Jira Data Center and Jira Cloud premium
+    /// plans. Other deployments respond with a 405, reported as an
+    /// `Error::InvalidInput` saying archiving is not supported, while unknown
+    /// projects stay `Error::NotFound`.
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/project-archiveProject)
+    /// for more information
+    pub fn archive<K>(&self, project_id_or_key: K) -> Result<()>
+    where
+        K: Into<String>,
+    {
+        self.jira
+            .request::<EmptyResponse>(
+                Method::PUT,
+                "api",
+                &format!("/project/{}/archive", project_id_or_key.into()),
+                None,
+            )
+            .map(|_| ())
+            .map_err(|error| match error {
+                Error::MethodNotAllowed => unsupported("archiving"),
+                error => error,
+            })
+    }
+
+    /// Restore an archived or deleted project
+    ///
+    /// Like archiving, restoring is not available on all deployments. Those
+    /// respond with a 405, reported as an `Error::InvalidInput` saying
+    /// restoring is not supported, while unknown projects stay `Error::NotFound`.
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/project-restoreProject)
+    /// for more information
+    pub fn restore<K>(&self, project_id_or_key: K) -> Result<Project>
+    where
+        K: Into<String>,
+    {
+        self.jira
+            .request(
+                Method::POST,
+                "api",
+                &format!("/project/{}/restore", project_id_or_key.into()),
+                None,
+            )
+            .map_err(|error| match error {
+                Error::MethodNotAllowed => unsupported("restoring"),
+                error => error,
+            })
+    }
+
+    /// Delete a project. With `enable_undo` the project is moved to the
+    /// recycle bin on Jira Cloud and can be restored later.
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/project-deleteProject)
+    /// for more information
+    pub fn delete<K>(&self, project_id_or_key: K, enable_undo: bool) -> Result<()>
+    where
+        K: Into<String>,
+    {
+        self.jira
+            .delete::<EmptyResponse>(
+                "api",
+                &format!(
+                    "/project/{}?enableUndo={enable_undo}",
+                    project_id_or_key.into()
+                ),
+            )
+            .map(|_| ())
+    }
+}
+
+/// Error for project operations the deployment doesn't offer
+fn unsupported(operation: &str) -> Error {
+    Error::InvalidInput(format!(
+        "project {operation} is not supported by this Jira deployment"
+    ))
+}
+
+/// Provides an iterator over multiple pages of project search results
+#[derive(Debug)]
+pub struct ProjectsIter<'a> {
+    pages: PageIter<ProjectPages<'a>>,
+}
+
+impl<'a> ProjectsIter<'a> {
+    fn new(options: &'a SearchOptions, jira: &Jira) -> Result<Self> {
+        let fetcher = ProjectPages {
+            jira: jira.clone(),
+            search_options: options,
+        };
+        let results = fetcher.jira.projects().search(options)?;
+        Ok(ProjectsIter {
+            pages: PageIter::new(results, fetcher),
+        })
+    }
+}
+
+impl<'a> Iterator for ProjectsIter<'a> {
+    type Item = Project;
+    fn next(&mut self) -> Option<Project> {
+        self.pages.next()
+    }
+}
+
+#[derive(Debug)]
+struct ProjectPages<'a> {
+    jira: Jira,
+    search_options: &'a SearchOptions,
+}
+
+impl<'a> PageFetcher for ProjectPages<'a> {
+    type Page = ProjectSearchResults;
+    fn fetch(&self, start_at: u64, max_results: u64) -> Result<ProjectSearchResults> {
+        self.jira.projects().search(
+            &self
+                .search_options
+                .as_builder()
+                .max_results(max_results)
+                .start_at(start_at)
+                .build(),
+        )
+    }
+}
+
+impl Paginated for ProjectSearchResults {
+    type Item = Project;
+
+    fn start_at(&self) -> u64 {
+        self.start_at
+    }
+
+    fn max_results(&self) -> u64 {
+        self.max_results
+    }
+
+    fn total(&self) -> Option<u64> {
+        Some(self.total)
+    }
+
+    fn is_last(&self) -> Option<bool> {
+        self.is_last
+    }
+
+    fn items(&self) -> &[Project] {
+        &self.values
+    }
+
+    fn into_items(self) -> Vec<Project> {
+        self.values
+    }
+}
@@ -1,21 +1,89 @@
 // Third party
 
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
-use std::collections::BTreeMap;
-use time::{format_description::well_known::Iso8601, OffsetDateTime};
+use std::collections::{BTreeMap, HashMap};
+use time::OffsetDateTime;
 use tracing::error;
+use url::ParseError;
 
 // Ours
-use crate::{Jira, Result};
+use crate::{jira_datetime, Error, FieldMeta, Jira, Result};
 
 /// Represents an general jira error response
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Errors {
-    #[serde(rename = "errorMessages")]
+    #[serde(default, rename = "errorMessages")]
     pub error_messages: Vec<String>,
+    /// Messages by field name
+    #[serde(default)]
     pub errors: BTreeMap<String, String>,
+    /// Single message some endpoints, e.g. of the v3 api, respond with instead
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
 }
 
+impl Errors {
+    /// Message for a single field, e.g. `summary`
+    pub fn for_field(&self, name: &str) -> Option<&str> {
+        self.errors.get(name).map(|message| message.as_str())
+    }
+
+    /// All messages of the response, field messages prefixed with the field name
+    pub fn all_messages(&self) -> Vec<String> {
+        self.error
+            .iter()
+            .chain(self.error_messages.iter())
+            .cloned()
+            .chain(
+                self.errors
+                    .iter()
+                    .map(|(field, message)| format!("{field}: {message}")),
+            )
+            .collect()
+    }
+
+    /// Parses an error response body, keeping a body which is not a jira
+    /// error document as single error message
+    pub(crate) fn from_body(body: &[u8]) -> Errors {
+        let parsed = serde_json::from_slice::<serde_json::Value>(body)
+            .ok()
+            .filter(|value| {
+                ["errorMessages", "errors", "error"]
+                    .iter()
+                    .any(|key| value.get(key).is_some())
+            })
+            .and_then(|value| serde_json::from_value::<Errors>(value).ok());
+        parsed.unwrap_or_else(|| {
+            let message = String::from_utf8_lossy(body).trim().to_owned();
+            Errors {
+                error_messages: if message.is_empty() {
+                    vec![]
+                } else {
+                    vec![message]
+                },
+                errors: BTreeMap::new(),
+                error: None,
+            }
+        })
+    }
+}
+
+impl std::fmt::Display for Errors {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let messages = self.all_messages();
+        if messages.is_empty() {
+            write!(f, "no error details")
+        } else {
+            write!(f, "{}", messages.join("; "))
+        }
+    }
+}
+
+impl std::error::Error for Errors {}
+
+/// System fields Jira doesn't return for `*navigable`
+const NON_NAVIGABLE_FIELDS: [&str; 3] = ["attachment", "comment", "worklog"];
+
 /// Represents a single jira issue
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Issue {
@@ -24,9 +92,76 @@ pub struct Issue {
     pub key: String,
     pub id: String,
     pub fields: BTreeMap<String, ::serde_json::Value>,
+    /// Changelog of the issue, present when requested with `expand=changelog`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub changelog: Option<Changelog>,
+    /// HTML renderings of the fields, present when requested with `expand=renderedFields`
+    #[serde(
+        default,
+        rename = "renderedFields",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub rendered_fields: Option<BTreeMap<String, ::serde_json::Value>>,
+    /// Entity properties, present when requested with the `properties` option
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<BTreeMap<String, ::serde_json::Value>>,
+    /// Field names by field id, present when requested with `expand=names`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub names: Option<BTreeMap<String, String>>,
+    /// Field types by field id, present when requested with `expand=schema`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<BTreeMap<String, FieldSchema>>,
+    /// Operations available on the issue, present when requested with `expand=operations`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operations: Option<Operations>,
+    /// Field tokens the issue was fetched with, `None` when it wasn't fetched
+    /// on its own, e.g. a subtask embedded in another issue
+    #[serde(skip)]
+    pub(crate) requested_fields: Option<Vec<String>>,
+    /// Expansions the issue was returned with, e.g. `renderedFields,names`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expand: Option<String>,
+    /// Top level keys not modelled above, kept for debugging and forward compatibility
+    ///
+    /// Only available with the `extra-keys` feature, which costs a slower
+    /// deserialization of every issue. Without it unknown keys are ignored.
+    #[cfg(feature = "extra-keys")]
+    #[serde(flatten, default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra: BTreeMap<String, ::serde_json::Value>,
 }
 
 impl Issue {
+    /// Whether `field` was part of the fields the issue was fetched with
+    ///
+    /// Tells a field which wasn't requested apart from one which is empty on
+    /// the server, as [`Issue::field`] returns `None` for both. Issues fetched
+    /// with `*all`, or with [`Issues::get`](crate::issues::Issues::get) and no
+    /// `fields` option, report every field as requested unless it was
+    /// excluded with `-field`. `*navigable`, the default of searches and of
+    /// board and sprint issue listings, leaves out the `attachment`,
+    /// `comment` and `worklog` fields.
+    ///
+    /// Issues embedded in others, like subtasks, parents and linked issues,
+    /// only carry the few fields Jira picks for them and report no field as
+    /// requested.
+    pub fn was_requested(&self, field: &str) -> bool {
+        let requested = match self.requested_fields {
+            Some(ref requested) => requested,
+            None => return false,
+        };
+        if requested
+            .iter()
+            .any(|token| token.strip_prefix('-') == Some(field))
+        {
+            return false;
+        }
+        requested.iter().any(|token| match token.as_str() {
+            "*all" => true,
+            "*navigable" => !NON_NAVIGABLE_FIELDS.contains(&field),
+            token => token == field,
+        })
+    }
+
     /// Resolves a typed field from an issues lists of arbitrary fields
     pub fn field<F>(&self, name: &str) -> Option<Result<F>>
     where
@@ -37,6 +172,61 @@ impl Issue {
             .map(|value| Ok(serde_json::value::from_value::<F>(value.clone())?))
     }
 
+    /// Resolves a typed field from the rendered fields, e.g. the HTML of the description
+    ///
+    /// Returns `None` unless the issue was fetched with `expand=renderedFields`
+    pub fn rendered<F>(&self, name: &str) -> Option<Result<F>>
+    where
+        for<'de> F: Deserialize<'de>,
+    {
+        self.rendered_fields
+            .as_ref()?
+            .get(name)
+            .map(|value| Ok(serde_json::value::from_value::<F>(value.clone())?))
+    }
+
+    /// Resolves a typed field by its name, e.g. `Story Points`
+    ///
+    /// Names are only known when the issue was fetched with `expand=names`;
+    /// without them `None` is returned.
+    pub fn field_by_name<F>(&self, name: &str) -> Option<Result<F>>
+    where
+        for<'de> F: Deserialize<'de>,
+    {
+        let id = self
+            .names
+            .as_ref()?
+            .iter()
+            .find(|(_, field_name)| field_name.as_str() == name)
+            .map(|(id, _)| id)?;
+        self.field(id)
+    }
+
+    /// Resolves a typed entity property
+    ///
+    /// Returns `None` unless the property was requested with the `properties` option
+    pub fn property<F>(&self, key: &str) -> Option<Result<F>>
+    where
+        for<'de> F: Deserialize<'de>,
+    {
+        self.properties
+            .as_ref()?
+            .get(key)
+            .map(|value| Ok(serde_json::value::from_value::<F>(value.clone())?))
+    }
+
+    /// Iterates over all fields of the issue
+    pub fn all_fields(&self) -> impl Iterator<Item = (&String, &::serde_json::Value)> {
+        self.fields.iter()
+    }
+
+    /// Iterates over the custom fields of the issue, i.e. those prefixed with `customfield_`
+    pub fn custom_fields(&self) -> impl Iterator<Item = (&String, &::serde_json::Value)> {
+        self.fields
+            .iter()
+            .filter(|(name, _)| name.starts_with("customfield_"))
+    }
+
     fn user_field(&self, name: &str) -> Option<Result<User>> {
         self.field::<User>(name)
     }
@@ -76,9 +266,21 @@ impl Issue {
             .and_then(|value| value.ok())
     }
 
+    /// Resolves a date or timestamp field, accepting Jira's `+0000` style
+    /// offsets, ISO 8601 and plain dates like `2024-01-31`
+    pub fn datetime_field(&self, name: &str) -> Option<Result<OffsetDateTime>> {
+        self.string_field(name).map(|value| {
+            value.and_then(|value| {
+                jira_datetime::parse(&value)
+                    .map_err(<serde_json::Error as serde::de::Error>::custom)
+                    .map_err(Error::from)
+            })
+        })
+    }
+
     fn extract_offset_date_time(&self, field: &str) -> Option<OffsetDateTime> {
         match self.string_field(field) {
-            Some(Ok(created)) => match OffsetDateTime::parse(created.as_ref(), &Iso8601::DEFAULT) {
+            Some(Ok(created)) => match jira_datetime::parse(&created) {
                 Ok(offset_date_time) => Some(offset_date_time),
                 Err(error) => {
                     error!(
@@ -137,6 +339,32 @@ impl Issue {
         self.field::<Vec<IssueLink>>("issuelinks") //.and_then(|value| value.ok()).unwrap_or(vec![])
     }
 
+    /// Linked issues, each described from the point of view of this issue
+    ///
+    /// For a `Blocks` link to an outward issue the relation reads "blocks",
+    /// for one to an inward issue "is blocked by". Links which fail to
+    /// deserialize are skipped.
+    pub fn related_issues(&self) -> Vec<RelatedIssue> {
+        self.links()
+            .and_then(|links| links.ok())
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|link| {
+                let (issue, relation, direction) = match (link.outward_issue, link.inward_issue) {
+                    (Some(issue), _) => (issue, link.link_type.outward, LinkDirection::Outward),
+                    (None, Some(issue)) => (issue, link.link_type.inward, LinkDirection::Inward),
+                    (None, None) => return None,
+                };
+                Some(RelatedIssue {
+                    key: issue.key,
+                    relation,
+                    link_type: link.link_type.name,
+                    direction,
+                })
+            })
+            .collect()
+    }
+
     pub fn project(&self) -> Option<Project> {
         self.field::<Project>("project")
             .and_then(|value| value.ok())
@@ -162,19 +390,132 @@ impl Issue {
         self.field::<Issue>("parent").and_then(|value| value.ok())
     }
 
+    /// Key of the parent issue, without deserializing the parent itself
+    pub fn parent_key(&self) -> Option<String> {
+        self.fields
+            .get("parent")?
+            .get("key")?
+            .as_str()
+            .map(|key| key.to_owned())
+    }
+
+    /// Key of the epic the issue belongs to, read from the epic link custom
+    /// field with the given id, e.g. `customfield_10014`
+    ///
+    /// The field holds the epic key directly on Jira Server, while some
+    /// setups return an object with a `key`; both are supported.
+    pub fn epic_key(&self, field_id: &str) -> Option<String> {
+        let value = self.fields.get(field_id)?;
+        value
+            .as_str()
+            .or_else(|| value.get("key")?.as_str())
+            .map(|key| key.to_owned())
+    }
+
+    /// Whether the issue is a subtask, according to its issue type
+    pub fn is_subtask(&self) -> bool {
+        self.issue_type()
+            .map(|issue_type| issue_type.subtask)
+            .unwrap_or(false)
+    }
+
+    /// Subtasks of the issue
+    pub fn subtasks(&self) -> Vec<Issue> {
+        self.field::<Vec<Issue>>("subtasks")
+            .and_then(|value| value.ok())
+            .unwrap_or_default()
+    }
+
+    /// Changelog embedded in the issue, when fetched with `expand=changelog`
+    ///
+    /// Unlike [`Issues::changelog`](crate::issues::Issues::changelog) this
+    /// doesn't issue another request.
+    pub fn changelog_inline(&self) -> Option<Changelog> {
+        self.changelog.clone()
+    }
+
+    /// Operations the current user may perform on the issue, e.g. to build
+    /// an action menu, when fetched with `expand=operations`
+    pub fn operations(&self) -> Option<Operations> {
+        self.operations.clone()
+    }
+
     pub fn timetracking(&self) -> Option<TimeTracking> {
         self.field::<TimeTracking>("timetracking")
             .and_then(|value| value.ok())
     }
 
+    /// Watchers summary, when the `watches` field was requested
+    pub fn watches(&self) -> Option<Watches> {
+        self.field::<Watches>("watches")
+            .and_then(|value| value.ok())
+    }
+
+    /// Votes summary, when the `votes` field was requested
+    pub fn votes(&self) -> Option<Votes> {
+        self.field::<Votes>("votes").and_then(|value| value.ok())
+    }
+
+    /// Number of users watching the issue
+    pub fn watch_count(&self) -> Option<u32> {
+        self.watches().map(|watches| watches.watch_count)
+    }
+
+    /// Whether the current user watches the issue
+    pub fn is_watching(&self) -> Option<bool> {
+        self.watches().map(|watches| watches.is_watching)
+    }
+
+    /// Number of votes for the issue
+    pub fn vote_count(&self) -> Option<u32> {
+        self.votes().map(|votes| votes.votes)
+    }
+
+    /// Whether the current user voted for the issue
+    pub fn has_voted(&self) -> Option<bool> {
+        self.votes().map(|votes| votes.has_voted)
+    }
+
+    /// Original estimate in seconds, from the time tracking field
+    pub fn original_estimate_seconds(&self) -> Option<u64> {
+        self.timetracking()
+            .and_then(|tracking| tracking.original_estimate_seconds)
+    }
+
+    /// Remaining estimate in seconds, from the time tracking field
+    pub fn remaining_estimate_seconds(&self) -> Option<u64> {
+        self.timetracking()
+            .and_then(|tracking| tracking.remaining_estimate_seconds)
+    }
+
+    /// Time spent in seconds, from the time tracking field
+    pub fn time_spent_seconds(&self) -> Option<u64> {
+        self.timetracking()
+            .and_then(|tracking| tracking.time_spent_seconds)
+    }
+
+    /// Url of the issue in the Jira web interface
+    ///
+    /// Falls back to appending `browse/{key}` to the host when the url can't
+    /// be resolved, see [`Issue::try_permalink`] to get the error instead.
     pub fn permalink(&self, jira: &Jira) -> String {
-        //format!("{}/browse/{}", jira.host, self.key)
-        jira.host
-            .join("/browse/")
-            .unwrap()
-            .join(&self.key)
-            .unwrap()
-            .to_string()
+        self.try_permalink(jira).unwrap_or_else(|_| {
+            format!(
+                "{}/browse/{}",
+                jira.core.host.as_str().trim_end_matches('/'),
+                self.key
+            )
+        })
+    }
+
+    /// Url of the issue in the Jira web interface, with the key percent-encoded
+    pub fn try_permalink(&self, jira: &Jira) -> Result<String> {
+        let mut url = jira.core.host.join("browse/")?;
+        url.path_segments_mut()
+            .map_err(|_| ParseError::RelativeUrlWithCannotBeABaseBase)?
+            .pop_if_empty()
+            .push(&self.key);
+        Ok(url.to_string())
     }
 
     pub fn try_from_custom_issue<S: Serialize>(custom_issue: &S) -> serde_json::Result<Self> {
@@ -203,6 +544,16 @@ pub struct Attachment {
     pub thumbnail: Option<String>,
 }
 
+impl Attachment {
+    /// Creation time, parsed from `created`
+    ///
+    /// Returns `None` if the server sent a format which can't be parsed; the
+    /// raw value stays available in `created`.
+    pub fn created_at(&self) -> Option<OffsetDateTime> {
+        jira_datetime::parse(&self.created).ok()
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Comments {
     pub comments: Vec<Comment>,
@@ -223,14 +574,58 @@ pub struct Comment {
     pub author: Option<User>,
     #[serde(rename = "updateAuthor")]
     pub update_author: Option<User>,
-    #[serde(default, with = "time::serde::iso8601::option")]
+    #[serde(default, with = "jira_datetime::option")]
     pub created: Option<OffsetDateTime>,
-    #[serde(default, with = "time::serde::iso8601::option")]
+    #[serde(default, with = "jira_datetime::option")]
     pub updated: Option<OffsetDateTime>,
     pub body: String,
     pub visibility: Option<Visibility>,
 }
 
+impl Comments {
+    /// Comments which members of the given role can see: unrestricted
+    /// comments and those restricted to that role. Comments restricted to a
+    /// group are left out, as group membership is not known here.
+    pub fn visible_to_role(&self, role: &str) -> Vec<&Comment> {
+        self.comments
+            .iter()
+            .filter(|comment| match comment.visibility {
+                None => true,
+                Some(ref visibility) => {
+                    visibility.visibility_type == "role" && visibility.value == role
+                }
+            })
+            .collect()
+    }
+}
+
+impl Comment {
+    /// Whether the visibility of the comment is restricted to a role or group
+    pub fn is_restricted(&self) -> bool {
+        self.visibility.is_some()
+    }
+}
+
+/// Watchers summary of an issue
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Watches {
+    #[serde(rename = "self")]
+    pub self_link: String,
+    pub watch_count: u32,
+    pub is_watching: bool,
+}
+
+/// Votes summary of an issue
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Votes {
+    #[serde(rename = "self")]
+    pub self_link: String,
+    pub votes: u32,
+    pub has_voted: bool,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Visibility {
     #[serde(rename = "type")]
@@ -238,9 +633,82 @@ pub struct Visibility {
     pub value: String,
 }
 
+impl Visibility {
+    /// Restricts visibility to members of a project role
+    pub fn role<R>(role: R) -> Visibility
+    where
+        R: Into<String>,
+    {
+        Visibility {
+            visibility_type: "role".to_owned(),
+            value: role.into(),
+        }
+    }
+
+    /// Restricts visibility to members of a group
+    pub fn group<G>(group: G) -> Visibility
+    where
+        G: Into<String>,
+    {
+        Visibility {
+            visibility_type: "group".to_owned(),
+            value: group.into(),
+        }
+    }
+}
+
+/// Operations available on an issue, grouped like in the Jira UI
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Operations {
+    #[serde(rename = "linkGroups", default)]
+    pub link_groups: Vec<LinkGroup>,
+}
+
+impl Operations {
+    /// Links of all groups, including nested ones, in order
+    pub fn links(&self) -> Vec<&SimpleLink> {
+        fn collect<'a>(groups: &'a [LinkGroup], links: &mut Vec<&'a SimpleLink>) {
+            for group in groups {
+                links.extend(&group.links);
+                collect(&group.groups, links);
+            }
+        }
+        let mut links = vec![];
+        collect(&self.link_groups, &mut links);
+        links
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LinkGroup {
+    pub id: Option<String>,
+    #[serde(rename = "styleClass")]
+    pub style_class: Option<String>,
+    pub header: Option<SimpleLink>,
+    pub weight: Option<i64>,
+    #[serde(default)]
+    pub links: Vec<SimpleLink>,
+    #[serde(default)]
+    pub groups: Vec<LinkGroup>,
+}
+
+/// A single operation of [`Operations`]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SimpleLink {
+    pub id: Option<String>,
+    #[serde(rename = "styleClass")]
+    pub style_class: Option<String>,
+    #[serde(rename = "iconClass")]
+    pub icon_class: Option<String>,
+    pub label: Option<String>,
+    pub title: Option<String>,
+    pub href: Option<String>,
+    pub weight: Option<i64>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Changelog {
-    #[serde(rename = "values")]
+    #[serde(rename = "values", alias = "histories")]
     pub histories: Vec<History>,
 }
 
@@ -251,6 +719,16 @@ pub struct History {
     pub items: Vec<HistoryItem>,
 }
 
+impl History {
+    /// Time of the change, parsed from `created`
+    ///
+    /// Returns `None` if the server sent a format which can't be parsed; the
+    /// raw value stays available in `created`.
+    pub fn created_at(&self) -> Option<OffsetDateTime> {
+        jira_datetime::parse(&self.created).ok()
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct HistoryItem {
     pub field: String,
@@ -267,6 +745,32 @@ pub struct Project {
     pub id: String,
     pub key: String,
     pub name: String,
+    /// Keys not modelled above, e.g. `self` or `avatarUrls`
+    ///
+    /// Only available with the `extra-keys` feature, see [`Issue::extra`].
+    #[cfg(feature = "extra-keys")]
+    #[serde(flatten, default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra: BTreeMap<String, ::serde_json::Value>,
+}
+
+/// Type of a field, as returned with `expand=schema`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldSchema {
+    /// Type of the value, e.g. `string`, `number`, `array` or `user`
+    #[serde(rename = "type")]
+    pub type_name: String,
+    /// Type of the elements of `array` fields
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub items: Option<String>,
+    /// Name of a system field, e.g. `summary`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system: Option<String>,
+    /// Key of the custom field type, e.g. `com.atlassian.jira.plugin.system.customfieldtypes:float`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_id: Option<u64>,
 }
 
 /// Represents link relationship between issues
@@ -283,6 +787,27 @@ pub struct IssueLink {
     pub link_type: LinkType,
 }
 
+/// An issue linked to another one, see `Issue::related_issues`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelatedIssue {
+    /// Key of the linked issue
+    pub key: String,
+    /// Phrase describing the relation, e.g. "blocks" or "is blocked by"
+    pub relation: String,
+    /// Name of the link type, e.g. "Blocks"
+    pub link_type: String,
+    pub direction: LinkDirection,
+}
+
+/// Direction of an issue link as seen from the issue it is listed on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LinkDirection {
+    /// The linked issue is the outward issue, e.g. the one being blocked
+    Outward,
+    /// The linked issue is the inward issue, e.g. the blocking one
+    Inward,
+}
+
 /// Represents type of issue relation
 #[derive(Serialize, Deserialize, Debug)]
 pub struct LinkType {
@@ -328,6 +853,9 @@ pub struct VersionUpdateBody {
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct User {
+    /// Identifier of the user on Jira Cloud
+    #[serde(default, rename = "accountId", skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
     pub active: bool,
     #[serde(rename = "avatarUrls")]
     pub avatar_urls: Option<BTreeMap<String, String>>,
@@ -343,7 +871,7 @@ pub struct User {
     pub timezone: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Status {
     pub description: String,
     #[serde(rename = "iconUrl")]
@@ -366,6 +894,7 @@ pub struct Priority {
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct IssueType {
+    #[serde(default)]
     pub description: String,
     #[serde(rename = "iconUrl")]
     pub icon_url: String,
@@ -376,6 +905,33 @@ pub struct IssueType {
     pub subtask: bool,
 }
 
+impl IssueType {
+    /// Maps the name of one of Jira's default issue types to a variant
+    ///
+    /// Names are compared case-insensitively. Custom issue types return
+    /// `None`; their name stays available in `name`.
+    pub fn standard(&self) -> Option<StandardIssueType> {
+        match self.name.to_lowercase().as_str() {
+            "bug" => Some(StandardIssueType::Bug),
+            "task" => Some(StandardIssueType::Task),
+            "story" => Some(StandardIssueType::Story),
+            "epic" => Some(StandardIssueType::Epic),
+            "sub-task" | "subtask" => Some(StandardIssueType::Subtask),
+            _ => None,
+        }
+    }
+}
+
+/// Jira's default issue types, see `IssueType::standard`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StandardIssueType {
+    Bug,
+    Task,
+    Story,
+    Epic,
+    Subtask,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct SearchResults {
     pub total: u64,
@@ -387,6 +943,113 @@ pub struct SearchResults {
     pub issues: Vec<Issue>,
 }
 
+impl SearchResults {
+    /// Indexes the issues of this page by their key
+    pub fn by_key(&self) -> HashMap<&str, &Issue> {
+        self.issues
+            .iter()
+            .map(|issue| (issue.key.as_str(), issue))
+            .collect()
+    }
+
+    /// Consumes the page, indexing its issues by their key
+    pub fn into_by_key(self) -> HashMap<String, Issue> {
+        self.issues
+            .into_iter()
+            .map(|issue| (issue.key.clone(), issue))
+            .collect()
+    }
+}
+
+/// Fields, functions and reserved words usable in JQL
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct JqlAutocompleteData {
+    #[serde(default)]
+    pub visible_field_names: Vec<JqlField>,
+    #[serde(default)]
+    pub visible_function_names: Vec<JqlFunction>,
+    #[serde(default)]
+    pub jql_reserved_words: Vec<String>,
+}
+
+/// A field as referenced in JQL, e.g. `status` or `cf[10010]`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct JqlField {
+    pub value: String,
+    pub display_name: String,
+    #[serde(default)]
+    pub orderable: Option<String>,
+    #[serde(default)]
+    pub searchable: Option<String>,
+    /// Whether values of the field can be suggested
+    #[serde(default)]
+    pub auto: Option<String>,
+    /// Id of the custom field, e.g. `cf[10010]`
+    #[serde(default)]
+    pub cfid: Option<String>,
+    #[serde(default)]
+    pub operators: Vec<String>,
+    #[serde(default)]
+    pub types: Vec<String>,
+}
+
+/// A function usable in JQL, e.g. `currentUser()`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct JqlFunction {
+    pub value: String,
+    pub display_name: String,
+    #[serde(default)]
+    pub is_list: Option<String>,
+    #[serde(default)]
+    pub types: Vec<String>,
+}
+
+/// Suggested values for a JQL field
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct JqlSuggestions {
+    pub results: Vec<JqlSuggestion>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct JqlSuggestion {
+    pub value: String,
+    /// Display name, which may contain html highlighting the matched part
+    pub display_name: String,
+}
+
+/// A group of issue picker results, e.g. recently viewed issues or
+/// issues matching the query
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct IssuePickerSection {
+    pub id: String,
+    pub label: String,
+    /// Summary of the section, e.g. `Showing 20 of 134 matching issues`
+    #[serde(default)]
+    pub sub: Option<String>,
+    pub issues: Vec<IssuePickerSuggestion>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IssuePickerSuggestion {
+    pub key: String,
+    /// Summary as plain text
+    #[serde(rename = "summaryText")]
+    pub summary: String,
+    /// Url of the issue type icon
+    #[serde(default)]
+    pub img: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct IssuePickerSuggestions {
+    pub sections: Vec<IssuePickerSection>,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct TimeTracking {
@@ -403,6 +1066,9 @@ pub struct TransitionOption {
     pub id: String,
     pub name: String,
     pub to: TransitionTo,
+    /// Fields of the transition screen by field id, listed with `expand=transitions.fields`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub fields: BTreeMap<String, FieldMeta>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -478,7 +1144,15 @@ impl TransitionTriggerOptionsBuilder {
     where
         R: Into<String>,
     {
-        self.field("resolution", Resolution { name: name.into() });
+        self.field(
+            "resolution",
+            Resolution {
+                id: None,
+                name: name.into(),
+                description: None,
+                self_link: None,
+            },
+        );
         self
     }
 
@@ -490,9 +1164,15 @@ impl TransitionTriggerOptionsBuilder {
     }
 }
 
-#[derive(Serialize, Debug, Deserialize)]
+#[derive(Serialize, Debug, Deserialize, Clone)]
 pub struct Resolution {
-    name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, rename = "self", skip_serializing_if = "Option::is_none")]
+    pub self_link: Option<String>,
 }
 
 #[derive(Serialize, Clone, Debug)]
@@ -500,6 +1180,36 @@ pub struct Transition {
     pub id: String,
 }
 
+/// Permissions of the current user
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MyPermissions {
+    pub permissions: BTreeMap<String, Permission>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Permission {
+    pub id: Option<String>,
+    pub key: String,
+    pub name: Option<String>,
+    #[serde(rename = "type")]
+    pub permission_type: Option<String>,
+    pub description: Option<String>,
+    pub have_permission: bool,
+}
+
+/// Information about the Jira instance
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerInfo {
+    pub base_url: String,
+    pub version: String,
+    pub build_number: Option<u64>,
+    /// `Cloud` or `Server`, not reported by older Jira Server versions
+    pub deployment_type: Option<String>,
+    pub server_title: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Session {
     pub name: String,
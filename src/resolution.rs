@@ -6,6 +6,38 @@ use std::collections::BTreeMap;
 // Ours
 use crate::{Jira, Result};
 
+/// Resolutions interface
+#[derive(Debug)]
+pub struct Resolutions {
+    jira: Jira,
+}
+
+impl Resolutions {
+    pub fn new(jira: &Jira) -> Resolutions {
+        Resolutions { jira: jira.clone() }
+    }
+
+    /// Returns all resolutions
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/resolution-getResolutions)
+    /// for more information
+    pub fn list(&self) -> Result<Vec<crate::Resolution>> {
+        self.jira.get("api", "/resolution")
+    }
+
+    /// Get a single resolution
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/resolution-getResolution)
+    /// for more information
+    pub fn get<I>(&self, id: I) -> Result<crate::Resolution>
+    where
+        I: Into<String>,
+    {
+        self.jira.get("api", &format!("/resolution/{}", id.into()))
+    }
+}
+
+#[deprecated(note = "use `Resolutions`, which returns the resolution itself")]
 #[derive(Debug)]
 pub struct Resolution {
     jira: Jira,
@@ -22,6 +54,7 @@ pub struct Resolved {
     pub additional_properties: bool,
 }
 
+#[allow(deprecated)]
 impl Resolution {
     pub fn new(jira: &Jira) -> Resolution {
         Resolution { jira: jira.clone() }
@@ -1,10 +1,14 @@
 //! Interfaces for searching for issues
 
 // Third party
+use tracing::warn;
 use url::form_urlencoded;
 
 // Ours
-use crate::{Issue, Jira, Result, SearchOptions, SearchResults};
+use crate::{
+    jql, Issue, IssuePickerSection, IssuePickerSuggestions, Jira, JqlAutocompleteData,
+    JqlSuggestions, PageFetcher, PageIter, Paginated, Result, SearchOptions, SearchResults,
+};
 
 /// Search interface
 #[derive(Debug)]
@@ -19,20 +23,110 @@ impl Search {
 
     /// Returns a single page of search results
     ///
+    /// `jql` replaces a `jql` parameter set on the options, which is not sent.
+    ///
     /// See the [jira docs](https://docs.atlassian.com/jira/REST/latest/#api/2/search)
     /// for more information
     pub fn list<J>(&self, jql: J, options: &SearchOptions) -> Result<SearchResults>
     where
         J: Into<String>,
     {
+        let issue_options = self.jira.issue_options(options).without_jql();
+        let mut path = vec!["/search".to_owned()];
+        let query_options = issue_options.serialize().unwrap_or_default();
+        let query = form_urlencoded::Serializer::new(query_options)
+            .append_pair("jql", &jql.into())
+            .finish();
+        path.push(query);
+        let mut results = self
+            .jira
+            .get::<SearchResults>("api", path.join("?").as_ref())?;
+        let fields = Some(issue_options.listed_fields());
+        for issue in &mut results.issues {
+            issue.requested_fields = fields.clone();
+        }
+        if let Some(requested) = options.max_results() {
+            if results.max_results < requested {
+                warn!(
+                    "Requested {} results per page, the server limited it to {}",
+                    requested, results.max_results
+                );
+            }
+        }
+        Ok(results)
+    }
+
+    /// Returns a single page of search results after checking the query with
+    /// [`jql::validate`](crate::jql::validate)
+    ///
+    /// Meant for queries built from user input: malformed queries fail with an
+    /// [`Error::InvalidInput`](crate::Error::InvalidInput) describing the problem
+    /// without being sent.
+    pub fn list_checked<J>(&self, jql: J, options: &SearchOptions) -> Result<SearchResults>
+    where
+        J: Into<String>,
+    {
+        let jql = jql.into();
+        jql::validate(&jql)?;
+        self.list(jql, options)
+    }
+
+    /// Returns the number of issues matching a query without fetching any of them
+    ///
+    /// See the [jira docs](https://docs.atlassian.com/jira/REST/latest/#api/2/search)
+    /// for more information
+    pub fn count<J>(&self, jql: J) -> Result<u64>
+    where
+        J: Into<String>,
+    {
+        let options = SearchOptions::builder().max_results(0).build();
+        Ok(self.list(jql, &options)?.total)
+    }
+
+    /// Whether any issue matches a query, without fetching any of them
+    ///
+    /// See the [jira docs](https://docs.atlassian.com/jira/REST/latest/#api/2/search)
+    /// for more information
+    pub fn exists<J>(&self, jql: J) -> Result<bool>
+    where
+        J: Into<String>,
+    {
+        Ok(self.count(jql)? > 0)
+    }
+
+    /// Returns a single page of search results, handing each issue to `on_issue`
+    /// as soon as it is parsed
+    ///
+    /// Unlike [`Search::list`] the response body is never buffered as a whole,
+    /// which keeps memory flat for very large pages.
+    /// As with [`Search::list`], `jql` replaces a `jql` parameter of the options.
+    ///
+    /// See the [jira docs](https://docs.atlassian.com/jira/REST/latest/#api/2/search)
+    /// for more information
+    #[cfg(feature = "streaming")]
+    pub fn stream<J, F>(
+        &self,
+        jql: J,
+        options: &SearchOptions,
+        mut on_issue: F,
+    ) -> Result<SearchPage>
+    where
+        J: Into<String>,
+        F: FnMut(Issue),
+    {
+        let options = self.jira.issue_options(options).without_jql();
         let mut path = vec!["/search".to_owned()];
         let query_options = options.serialize().unwrap_or_default();
         let query = form_urlencoded::Serializer::new(query_options)
             .append_pair("jql", &jql.into())
             .finish();
         path.push(query);
-        self.jira
-            .get::<SearchResults>("api", path.join("?").as_ref())
+        let response = self.jira.get_unread("api", path.join("?").as_ref())?;
+        let fields = Some(options.listed_fields());
+        streaming::read_page(std::io::BufReader::new(response), |mut issue: Issue| {
+            issue.requested_fields = fields.clone();
+            on_issue(issue)
+        })
     }
 
     /// Return a type which may be used to iterate over consecutive pages of results
@@ -45,15 +139,173 @@ impl Search {
     {
         Iter::new(jql, options, &self.jira)
     }
+
+    /// Get issues matching a text, e.g. for a type-ahead, grouped into
+    /// sections like the recently viewed issues and the search results
+    ///
+    /// `current_jql` restricts the matching issues further.
+    ///
+    /// See the [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-getIssuePickerResource)
+    /// for more information
+    pub fn picker<Q>(
+        &self,
+        query: Q,
+        current_jql: Option<String>,
+    ) -> Result<Vec<IssuePickerSection>>
+    where
+        Q: Into<String>,
+    {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        params.append_pair("query", &query.into());
+        if let Some(jql) = current_jql {
+            params.append_pair("currentJQL", &jql);
+        }
+        self.jira
+            .get::<IssuePickerSuggestions>("api", &format!("/issue/picker?{}", params.finish()))
+            .map(|suggestions| suggestions.sections)
+    }
+
+    /// Get the fields, operators and functions which may be used in JQL
+    ///
+    /// See the [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/jql/autocompletedata-getAutoComplete)
+    /// for more information
+    pub fn autocomplete_data(&self) -> Result<JqlAutocompleteData> {
+        self.jira.get("api", "/jql/autocompletedata")
+    }
+
+    /// Get suggested values of a field, matching the start of `field_value`
+    ///
+    /// See the [jira docs](https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/jql/autocompletedata-getFieldAutoCompleteForQueryString)
+    /// for more information
+    pub fn autocomplete_suggestions<N, V>(
+        &self,
+        field_name: N,
+        field_value: V,
+    ) -> Result<JqlSuggestions>
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        let query = form_urlencoded::Serializer::new(String::new())
+            .append_pair("fieldName", &field_name.into())
+            .append_pair("fieldValue", &field_value.into())
+            .finish();
+        self.jira.get(
+            "api",
+            &format!("/jql/autocompletedata/suggestions?{}", query),
+        )
+    }
+}
+
+/// Pagination details of a page read with [`Search::stream`]
+#[cfg(feature = "streaming")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchPage {
+    pub total: u64,
+    pub max_results: u64,
+    pub start_at: u64,
+    /// Number of issues handed to the callback
+    pub returned: u64,
+}
+
+#[cfg(feature = "streaming")]
+mod streaming {
+    use std::fmt;
+    use std::io::Read;
+
+    use serde::de::{DeserializeSeed, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+
+    use super::SearchPage;
+    use crate::{Issue, Result};
+
+    pub(super) fn read_page<R, F>(reader: R, mut on_issue: F) -> Result<SearchPage>
+    where
+        R: Read,
+        F: FnMut(Issue),
+    {
+        let mut deserializer = serde_json::Deserializer::from_reader(reader);
+        let page = PageSeed(&mut on_issue).deserialize(&mut deserializer)?;
+        deserializer.end()?;
+        Ok(page)
+    }
+
+    /// Reads the page metadata, passing the issues on without collecting them
+    struct PageSeed<'a, F>(&'a mut F);
+
+    impl<'de, 'a, F: FnMut(Issue)> DeserializeSeed<'de> for PageSeed<'a, F> {
+        type Value = SearchPage;
+
+        fn deserialize<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> std::result::Result<SearchPage, D::Error> {
+            deserializer.deserialize_map(self)
+        }
+    }
+
+    impl<'de, 'a, F: FnMut(Issue)> Visitor<'de> for PageSeed<'a, F> {
+        type Value = SearchPage;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a page of search results")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(
+            self,
+            mut map: A,
+        ) -> std::result::Result<SearchPage, A::Error> {
+            let mut page = SearchPage::default();
+            while let Some(key) = map.next_key::<String>()? {
+                match key.as_str() {
+                    "total" => page.total = map.next_value()?,
+                    "maxResults" => page.max_results = map.next_value()?,
+                    "startAt" => page.start_at = map.next_value()?,
+                    "issues" => page.returned = map.next_value_seed(IssuesSeed(&mut *self.0))?,
+                    _ => {
+                        map.next_value::<IgnoredAny>()?;
+                    }
+                }
+            }
+            Ok(page)
+        }
+    }
+
+    /// Hands each issue of the array to the callback, returning their count
+    struct IssuesSeed<'a, F>(&'a mut F);
+
+    impl<'de, 'a, F: FnMut(Issue)> DeserializeSeed<'de> for IssuesSeed<'a, F> {
+        type Value = u64;
+
+        fn deserialize<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> std::result::Result<u64, D::Error> {
+            deserializer.deserialize_seq(self)
+        }
+    }
+
+    impl<'de, 'a, F: FnMut(Issue)> Visitor<'de> for IssuesSeed<'a, F> {
+        type Value = u64;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("an array of issues")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> std::result::Result<u64, A::Error> {
+            let mut count = 0;
+            while let Some(issue) = seq.next_element::<Issue>()? {
+                (self.0)(issue);
+                count += 1;
+            }
+            Ok(count)
+        }
+    }
 }
 
 /// Provides an iterator over multiple pages of search results
 #[derive(Debug)]
 pub struct Iter<'a> {
-    jira: Jira,
-    jql: String,
-    results: SearchResults,
-    search_options: &'a SearchOptions,
+    pages: PageIter<SearchPages<'a>>,
 }
 
 impl<'a> Iter<'a> {
@@ -61,44 +313,69 @@ impl<'a> Iter<'a> {
     where
         J: Into<String>,
     {
-        let query = jql.into();
-        let results = jira.search().list(query.clone(), options)?;
-        Ok(Iter {
+        let fetcher = SearchPages {
             jira: jira.clone(),
-            jql: query,
-            results,
+            jql: jql.into(),
             search_options: options,
+        };
+        let results = fetcher
+            .jira
+            .search()
+            .list(fetcher.jql.clone(), fetcher.search_options)?;
+        Ok(Iter {
+            pages: PageIter::new(results, fetcher),
         })
     }
-
-    fn more(&self) -> bool {
-        (self.results.start_at + self.results.issues.len() as u64) < self.results.total
-    }
 }
 
 impl<'a> Iterator for Iter<'a> {
     type Item = Issue;
     fn next(&mut self) -> Option<Issue> {
-        self.results.issues.pop().or_else(|| {
-            if self.more() {
-                match self.jira.search().list(
-                    self.jql.clone(),
-                    &self
-                        .search_options
-                        .as_builder()
-                        .max_results(self.results.max_results)
-                        .start_at(self.results.start_at + self.results.max_results)
-                        .build(),
-                ) {
-                    Ok(new_results) => {
-                        self.results = new_results;
-                        self.results.issues.pop()
-                    }
-                    _ => None,
-                }
-            } else {
-                None
-            }
-        })
+        self.pages.next()
+    }
+}
+
+#[derive(Debug)]
+struct SearchPages<'a> {
+    jira: Jira,
+    jql: String,
+    search_options: &'a SearchOptions,
+}
+
+impl<'a> PageFetcher for SearchPages<'a> {
+    type Page = SearchResults;
+    fn fetch(&self, start_at: u64, max_results: u64) -> Result<SearchResults> {
+        self.jira.search().list(
+            self.jql.clone(),
+            &self
+                .search_options
+                .clone()
+                .with_max_results(max_results)
+                .with_start_at(start_at),
+        )
+    }
+}
+
+impl Paginated for SearchResults {
+    type Item = Issue;
+
+    fn start_at(&self) -> u64 {
+        self.start_at
+    }
+
+    fn max_results(&self) -> u64 {
+        self.max_results
+    }
+
+    fn total(&self) -> Option<u64> {
+        Some(self.total)
+    }
+
+    fn items(&self) -> &[Issue] {
+        &self.issues
+    }
+
+    fn into_items(self) -> Vec<Issue> {
+        self.issues
     }
 }
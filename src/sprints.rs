@@ -6,7 +6,10 @@ use tracing::info;
 use url::form_urlencoded;
 
 // Ours
-use crate::{Board, EmptyResponse, Jira, Result, SearchOptions};
+use crate::{
+    Board, EmptyResponse, Issue, IssueResults, Jira, PageFetcher, PageIter, Paginated, Result,
+    SearchOptions,
+};
 
 #[derive(Debug)]
 pub struct Sprints {
@@ -66,8 +69,6 @@ impl Sprints {
     ///
     /// See this [jira docs](https://docs.atlassian.com/jira-software/REST/9.5.0/#agile/1.0/sprint-createSprint)
     /// for more information
-    ///     `pub fn create<T: Into<String>>(&self, project_id: u64, name: T) -> Result<Version> {`
-
     pub fn create<T: Into<String>>(&self, board: Board, name: T) -> Result<Sprint> {
         let data: CreateSprint = CreateSprint {
             name: name.into(),
@@ -99,6 +100,40 @@ impl Sprints {
         self.jira.post("agile", &path, data)
     }
 
+    /// Returns a single page of the issues of a sprint
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/sprint-getIssuesForSprint)
+    /// for more information
+    pub fn issues(&self, sprint_id: u64, options: &SearchOptions) -> Result<IssueResults> {
+        let options = self.jira.issue_options(options);
+        let mut path = vec![format!("/sprint/{sprint_id}/issue")];
+        let query_options = options.serialize().unwrap_or_default();
+        let query = form_urlencoded::Serializer::new(query_options).finish();
+
+        path.push(query);
+
+        let mut results = self
+            .jira
+            .get::<IssueResults>("agile", path.join("?").as_ref())?;
+        let fields = Some(options.listed_fields());
+        for issue in &mut results.issues {
+            issue.requested_fields = fields.clone();
+        }
+        Ok(results)
+    }
+
+    /// Returns a type which may be used to iterate over all issues of a sprint
+    ///
+    /// See this [jira docs](https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/sprint-getIssuesForSprint)
+    /// for more information
+    pub fn issues_iter<'a>(
+        &self,
+        sprint_id: u64,
+        options: &'a SearchOptions,
+    ) -> Result<SprintIssuesIter<'a>> {
+        SprintIssuesIter::new(sprint_id, options, &self.jira)
+    }
+
     /// Returns a single page of sprint results
     ///
     /// See this [jira docs](https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/board/{boardId}/sprint-getAllSprints)
@@ -130,51 +165,135 @@ impl Sprints {
 /// Provides an iterator over multiple pages of search results
 #[derive(Debug)]
 pub struct SprintsIter<'a> {
-    jira: Jira,
-    board: &'a Board,
-    results: SprintResults,
-    search_options: &'a SearchOptions,
+    pages: PageIter<BoardSprints<'a>>,
 }
 
 impl<'a> SprintsIter<'a> {
     fn new(board: &'a Board, options: &'a SearchOptions, jira: &Jira) -> Result<Self> {
-        let results = jira.sprints().list(board, options)?;
-        Ok(SprintsIter {
-            board,
+        let fetcher = BoardSprints {
             jira: jira.clone(),
-            results,
+            board,
             search_options: options,
+        };
+        let results = fetcher.fetch_first()?;
+        Ok(SprintsIter {
+            pages: PageIter::new(results, fetcher),
         })
     }
-
-    fn more(&self) -> bool {
-        !self.results.is_last
-    }
 }
 
 impl<'a> Iterator for SprintsIter<'a> {
     type Item = Sprint;
     fn next(&mut self) -> Option<Sprint> {
-        self.results.values.pop().or_else(|| {
-            if self.more() {
-                match self.jira.sprints().list(
-                    self.board,
-                    &self
-                        .search_options
-                        .as_builder()
-                        .max_results(self.results.max_results)
-                        .start_at(self.results.start_at + self.results.max_results)
-                        .build(),
-                ) {
-                    Ok(new_results) => {
-                        self.results = new_results;
-                        self.results.values.pop()
-                    }
-                    _ => None,
-                }
-            } else {
-                None
-            }
+        self.pages.next()
+    }
+}
+
+#[derive(Debug)]
+struct BoardSprints<'a> {
+    jira: Jira,
+    board: &'a Board,
+    search_options: &'a SearchOptions,
+}
+
+impl<'a> BoardSprints<'a> {
+    fn fetch_first(&self) -> Result<SprintResults> {
+        self.jira.sprints().list(self.board, self.search_options)
+    }
+}
+
+impl<'a> PageFetcher for BoardSprints<'a> {
+    type Page = SprintResults;
+    fn fetch(&self, start_at: u64, max_results: u64) -> Result<SprintResults> {
+        self.jira.sprints().list(
+            self.board,
+            &self
+                .search_options
+                .as_builder()
+                .max_results(max_results)
+                .start_at(start_at)
+                .build(),
+        )
+    }
+}
+
+/// Provides an iterator over the issues of a sprint
+#[derive(Debug)]
+pub struct SprintIssuesIter<'a> {
+    pages: PageIter<SprintIssues<'a>>,
+}
+
+impl<'a> SprintIssuesIter<'a> {
+    fn new(sprint_id: u64, options: &'a SearchOptions, jira: &Jira) -> Result<Self> {
+        let fetcher = SprintIssues {
+            jira: jira.clone(),
+            sprint_id,
+            search_options: options,
+        };
+        let results = fetcher.fetch_first()?;
+        Ok(SprintIssuesIter {
+            pages: PageIter::new(results, fetcher),
         })
     }
 }
+
+impl<'a> Iterator for SprintIssuesIter<'a> {
+    type Item = Issue;
+    fn next(&mut self) -> Option<Issue> {
+        self.pages.next()
+    }
+}
+
+#[derive(Debug)]
+struct SprintIssues<'a> {
+    jira: Jira,
+    sprint_id: u64,
+    search_options: &'a SearchOptions,
+}
+
+impl<'a> SprintIssues<'a> {
+    fn fetch_first(&self) -> Result<IssueResults> {
+        self.jira
+            .sprints()
+            .issues(self.sprint_id, self.search_options)
+    }
+}
+
+impl<'a> PageFetcher for SprintIssues<'a> {
+    type Page = IssueResults;
+    fn fetch(&self, start_at: u64, max_results: u64) -> Result<IssueResults> {
+        self.jira.sprints().issues(
+            self.sprint_id,
+            &self
+                .search_options
+                .as_builder()
+                .max_results(max_results)
+                .start_at(start_at)
+                .build(),
+        )
+    }
+}
+
+impl Paginated for SprintResults {
+    type Item = Sprint;
+
+    fn start_at(&self) -> u64 {
+        self.start_at
+    }
+
+    fn max_results(&self) -> u64 {
+        self.max_results
+    }
+
+    fn is_last(&self) -> Option<bool> {
+        Some(self.is_last)
+    }
+
+    fn items(&self) -> &[Sprint] {
+        &self.values
+    }
+
+    fn into_items(self) -> Vec<Sprint> {
+        self.values
+    }
+}
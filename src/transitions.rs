@@ -1,7 +1,10 @@
 //! Interfaces for accessing and managing transition
 
 // Ours
-use crate::{Error, Jira, Result, TransitionOption, TransitionOptions, TransitionTriggerOptions};
+use crate::{
+    issues::validate_fields, Error, Jira, Result, TransitionOption, TransitionOptions,
+    TransitionTriggerOptions,
+};
 
 /// Issue transition interface
 #[derive(Debug)]
@@ -45,4 +48,37 @@ impl Transitions {
                 e => Err(e),
             })
     }
+
+    /// Trigger a transition after checking its fields against the transition screen
+    ///
+    /// The transition must be available for the issue, required fields of its
+    /// screen must be set, values restricted to allowed ones must use one of
+    /// them, and only fields of the screen, e.g. a resolution, may be set.
+    /// Problems are reported together as an [`Error::InvalidInput`] before
+    /// triggering anything.
+    pub fn trigger_validated(&self, trans: TransitionTriggerOptions) -> Result<()> {
+        let available = self.list()?;
+        let transition = available
+            .iter()
+            .find(|option| option.id == trans.transition.id)
+            .ok_or_else(|| {
+                Error::InvalidInput(format!(
+                    "transition {} is not available for {}",
+                    trans.transition.id, self.key
+                ))
+            })?;
+
+        let problems = validate_fields(
+            &transition.fields,
+            &trans.fields,
+            &format!("the screen of transition {}", transition.name),
+        );
+        if !problems.is_empty() {
+            return Err(Error::InvalidInput(format!(
+                "transition rejected: {}",
+                problems.join("; ")
+            )));
+        }
+        self.trigger(trans)
+    }
 }
@@ -1,4 +1,7 @@
-use crate::{Jira, Result, Version, VersionCreationBody, VersionMoveAfterBody, VersionUpdateBody};
+use crate::{
+    EmptyResponse, Jira, Result, Version, VersionCreationBody, VersionMoveAfterBody,
+    VersionUpdateBody,
+};
 
 pub struct Versions {
     jira: Jira,
@@ -28,6 +31,46 @@ impl Versions {
             .post("api", "/version", VersionCreationBody { project_id, name })
     }
 
+    /// Create a new version from a complete creation body
+    ///
+    /// See [jira docs](https://developer.atlassian.com/cloud/jira/platform/rest/v2/#api-rest-api-2-version-post)
+    /// for more information
+    pub fn create_from_body(&self, body: VersionCreationBody) -> Result<Version> {
+        self.jira.post("api", "/version", body)
+    }
+
+    /// Fetch a single version
+    ///
+    /// See [jira docs](https://developer.atlassian.com/cloud/jira/platform/rest/v2/#api-rest-api-2-version-id-get)
+    /// for more information
+    pub fn get<I: Into<String>>(&self, version_id: I) -> Result<Version> {
+        self.jira
+            .get("api", &format!("/version/{}", version_id.into()))
+    }
+
+    /// Update a version, returning it as updated
+    ///
+    /// See [jira docs](https://developer.atlassian.com/cloud/jira/platform/rest/v2/#api-rest-api-2-version-id-put)
+    /// for more information
+    pub fn update<I: Into<String>>(
+        &self,
+        version_id: I,
+        body: VersionUpdateBody,
+    ) -> Result<Version> {
+        self.jira
+            .put("api", &format!("/version/{}", version_id.into()), body)
+    }
+
+    /// Delete a version
+    ///
+    /// See [jira docs](https://developer.atlassian.com/cloud/jira/platform/rest/v2/#api-rest-api-2-version-id-delete)
+    /// for more information
+    pub fn delete<I: Into<String>>(&self, version_id: I) -> Result<()> {
+        self.jira
+            .delete::<EmptyResponse>("api", &format!("/version/{}", version_id.into()))
+            .map(|_| ())
+    }
+
     /// Move a version after another version
     ///
     /// See [jira docs](https://developer.atlassian.com/cloud/jira/platform/rest/v2/#api-rest-api-2-version-id-move-post)
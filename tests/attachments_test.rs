@@ -0,0 +1,79 @@
+extern crate gouqi;
+
+use gouqi::{Credentials, Error, Jira};
+use mockito::{Matcher, Server};
+use std::fs::File;
+use std::io::Write;
+
+#[test]
+fn upload_stream_from_file() {
+    let path = std::env::temp_dir().join(format!("gouqi-upload-{}.txt", std::process::id()));
+    File::create(&path)
+        .unwrap()
+        .write_all(b"streamed attachment content")
+        .unwrap();
+
+    let mut server = Server::new();
+    let upload = server
+        .mock("POST", "/rest/api/latest/issue/TEST-1/attachments")
+        .match_header("X-Atlassian-Token", "no-check")
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex("filename=\"report.txt\"".to_owned()),
+            Matcher::Regex("streamed attachment content".to_owned()),
+        ]))
+        .with_body(
+            r#"[{
+                "self": "http://jira/rest/api/2/attachment/10000",
+                "id": "10000",
+                "filename": "report.txt",
+                "author": {
+                    "self": "http://jira/rest/api/2/user?username=fred",
+                    "name": "fred",
+                    "displayName": "Fred",
+                    "active": true
+                },
+                "created": "2024-01-02T10:00:00.000+0000",
+                "size": 27,
+                "mimeType": "text/plain",
+                "content": "http://jira/secure/attachment/10000/report.txt"
+            }]"#,
+        )
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let attachments = jira
+        .attachments()
+        .upload_stream("TEST-1", "report.txt", File::open(&path).unwrap())
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    upload.assert();
+    assert_eq!(attachments.len(), 1);
+    assert_eq!(attachments[0].filename, "report.txt");
+    assert_eq!(attachments[0].size, 27);
+}
+
+#[test]
+fn download_from_another_host_is_refused() {
+    let mut server = Server::new();
+    let mut other = Server::new();
+    let foreign = other
+        .mock("GET", "/secure/attachment/10000/report.txt")
+        .expect(0)
+        .create();
+    let local = server.mock("GET", Matcher::Any).expect(0).create();
+
+    let jira = Jira::new(
+        server.url(),
+        Credentials::Basic("user".to_owned(), "secret".to_owned()),
+    )
+    .unwrap();
+    let result = jira.attachments().download(format!(
+        "{}/secure/attachment/10000/report.txt",
+        other.url()
+    ));
+
+    assert!(matches!(result, Err(Error::InvalidInput(_))));
+    foreign.assert();
+    local.assert();
+}
@@ -47,3 +47,156 @@ fn deserialise_board_results() {
     assert!(board_results.is_last);
     assert_eq!(board_results.values.len(), 1);
 }
+
+#[test]
+fn create_board() {
+    let mut server = mockito::Server::new();
+    let mock_server = server
+        .mock("POST", "/rest/agile/latest/board")
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "name": "TEST board",
+            "type": "scrum",
+            "filterId": 10040
+        })))
+        .with_status(201)
+        .with_body(
+            r#"{
+                "id": 84,
+                "self": "https://my.atlassian.net/rest/agile/1.0/board/84",
+                "name": "TEST board",
+                "type": "scrum",
+                "location": { "projectKey": "TEST", "projectId": 10000 }
+            }"#,
+        )
+        .create();
+
+    let jira = gouqi::Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+    let board = jira
+        .boards()
+        .create(CreateBoard {
+            name: "TEST board".to_owned(),
+            type_name: "scrum".to_owned(),
+            filter_id: 10040,
+        })
+        .unwrap();
+
+    mock_server.assert();
+    assert_eq!(board.id, 84);
+    assert_eq!(board.type_name, "scrum");
+    let location = board.location.unwrap();
+    assert_eq!(location.project_key.as_deref(), Some("TEST"));
+    assert_eq!(location.project_id, Some(10000));
+}
+
+#[test]
+fn delete_board() {
+    let mut server = mockito::Server::new();
+    let mock_server = server
+        .mock("DELETE", "/rest/agile/latest/board/84")
+        .with_status(204)
+        .create();
+
+    let jira = gouqi::Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+    jira.boards().delete(84).unwrap();
+
+    mock_server.assert();
+}
+
+#[test]
+fn get_board() {
+    let mut server = mockito::Server::new();
+    let mock_server = server
+        .mock("GET", "/rest/agile/latest/board/84")
+        .with_body(
+            r#"{"id": 84, "self": "http://jira/rest/agile/1.0/board/84", "name": "TEST board", "type": "scrum", "location": {"projectKey": "TEST"}}"#,
+        )
+        .create();
+
+    let jira = gouqi::Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+    let board = jira.boards().get("84").unwrap();
+
+    mock_server.assert();
+    assert_eq!(board.name, "TEST board");
+    assert_eq!(
+        board.location.and_then(|location| location.project_key),
+        Some("TEST".to_owned())
+    );
+}
+
+#[test]
+fn list_boards_of_a_project() {
+    let mut server = mockito::Server::new();
+    let mock_server = server
+        .mock("GET", "/rest/agile/latest/board")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("projectKeyOrId".into(), "TEST".into()),
+            mockito::Matcher::UrlEncoded("type".into(), "scrum".into()),
+        ]))
+        .with_body(
+            r#"{
+                "maxResults": 50,
+                "startAt": 0,
+                "isLast": true,
+                "values": [{"id": 84, "self": "http://jira/rest/agile/1.0/board/84", "name": "TEST board", "type": "scrum"}]
+            }"#,
+        )
+        .create();
+
+    let jira = gouqi::Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+    let options = gouqi::SearchOptions::builder()
+        .project_key_or_id("TEST")
+        .type_name("scrum")
+        .build();
+    let boards = jira.boards().list(&options).unwrap();
+
+    mock_server.assert();
+    assert_eq!(boards.values.len(), 1);
+    assert_eq!(boards.values[0].id, 84);
+}
+
+#[test]
+fn iterate_boards_of_a_project_keeps_the_filter() {
+    let mut server = mockito::Server::new();
+    let first = server
+        .mock("GET", "/rest/agile/latest/board")
+        .match_query(mockito::Matcher::Exact("projectKeyOrId=TEST".into()))
+        .with_body(
+            r#"{
+                "maxResults": 1,
+                "startAt": 0,
+                "isLast": false,
+                "values": [{"id": 1, "self": "http://jira/rest/agile/1.0/board/1", "name": "One", "type": "scrum"}]
+            }"#,
+        )
+        .create();
+    let second = server
+        .mock("GET", "/rest/agile/latest/board")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("projectKeyOrId".into(), "TEST".into()),
+            mockito::Matcher::UrlEncoded("startAt".into(), "1".into()),
+        ]))
+        .with_body(
+            r#"{
+                "maxResults": 1,
+                "startAt": 1,
+                "isLast": true,
+                "values": [{"id": 2, "self": "http://jira/rest/agile/1.0/board/2", "name": "Two", "type": "kanban"}]
+            }"#,
+        )
+        .create();
+
+    let jira = gouqi::Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+    let options = gouqi::SearchOptions::builder()
+        .project_key_or_id("TEST")
+        .build();
+    let ids = jira
+        .boards()
+        .iter(&options)
+        .unwrap()
+        .map(|board| board.id)
+        .collect::<Vec<_>>();
+
+    first.assert();
+    second.assert();
+    assert_eq!(ids, vec![1, 2]);
+}
@@ -32,13 +32,144 @@ macro_rules! builder_pattern {
 builder_pattern! {
     build_pattern_validate: (validate, true, "validateQuery", "true"),
     build_pattern_fields: (fields, vec!["field1", "field2"], "fields", "field1,field2"),
+    build_pattern_fields_tokens: (fields, vec!["*navigable", "-comment"], "fields", "*navigable,-comment"),
+    build_pattern_exclude_fields: (exclude_fields, vec!["comment", "attachment"], "fields", "*all,-comment,-attachment"),
     build_pattern_max_results: (max_results, 50, "maxResults", "50"),
     build_pattern_start_at: (start_at, 10, "startAt", "10"),
     build_pattern_type_name: (type_name, "my_type", "type", "my_type"),
     build_pattern_name: (name, "my_name", "name", "my_name"),
     build_pattern_project_key_or_id: (project_key_or_id, "1234", "projectKeyOrId", "1234"),
     build_pattern_expand: (expand, vec!["expand1", "expand2"], "expand", "expand1,expand2"),
+    build_pattern_properties: (properties, vec!["app.meta", "app.sync"], "properties", "app.meta,app.sync"),
+    build_pattern_query: (query, "TEST", "query", "TEST"),
     build_pattern_state: (state, "my_state", "state","my_state"),
     build_pattern_jql: (jql, "project = '1234'", "jql", "project = '1234'"),
     build_pattern_jalidate_query: (validate_query, true, "validateQuery", "true"),
 }
+
+fn decode(serialized: &str) -> Vec<(String, String)> {
+    form_urlencoded::parse(serialized.as_bytes())
+        .into_owned()
+        .collect()
+}
+
+#[test]
+fn empty_options_round_trip() {
+    let options = SearchOptions::default();
+
+    assert_eq!(options.serialize(), None);
+    assert_eq!(options.as_builder().build(), options);
+    assert_eq!(SearchOptions::builder().build(), options);
+}
+
+#[test]
+fn options_round_trip() {
+    let jqls = [
+        "project = TEST",
+        r#"summary ~ "quoted \"text\"" AND labels in (a, b)"#,
+        "assignee = currentUser() & status != Done % 100#",
+        "text ~ 'ünïcødé' ORDER BY created DESC",
+    ];
+    for jql in jqls {
+        let options = SearchOptions::builder()
+            .jql(jql)
+            .fields(vec!["summary", "customfield_10001", "-comment"])
+            .expand(vec!["changelog", "renderedFields"])
+            .max_results(25)
+            .start_at(50)
+            .validate_query(false)
+            .build();
+
+        assert_eq!(options.as_builder().build(), options);
+
+        let serialized = options.serialize().unwrap();
+        assert!(!serialized.contains(' '), "unencoded space in {serialized}");
+        assert_eq!(
+            decode(&serialized),
+            vec![
+                ("expand".to_owned(), "changelog,renderedFields".to_owned()),
+                (
+                    "fields".to_owned(),
+                    "summary,customfield_10001,-comment".to_owned()
+                ),
+                ("jql".to_owned(), jql.to_owned()),
+                ("maxResults".to_owned(), "25".to_owned()),
+                ("startAt".to_owned(), "50".to_owned()),
+                ("validateQuery".to_owned(), "false".to_owned()),
+            ]
+        );
+        assert_eq!(options.as_builder().build().serialize(), Some(serialized));
+    }
+}
+
+#[test]
+fn options_serialize_deterministically() {
+    let first = SearchOptions::builder()
+        .start_at(0)
+        .max_results(10)
+        .fields(vec!["summary"])
+        .build();
+    let second = SearchOptions::builder()
+        .fields(vec!["summary"])
+        .max_results(10)
+        .start_at(0)
+        .build();
+
+    assert_eq!(first, second);
+    assert_eq!(first.serialize(), second.serialize());
+}
+
+#[test]
+fn as_builder_overrides_only_changed_params() {
+    let options = SearchOptions::builder()
+        .jql("project = TEST")
+        .start_at(0)
+        .build();
+    let next = options.as_builder().start_at(50).build();
+
+    assert_ne!(next, options);
+    assert_eq!(
+        decode(&next.serialize().unwrap()),
+        vec![
+            ("jql".to_owned(), "project = TEST".to_owned()),
+            ("startAt".to_owned(), "50".to_owned()),
+        ]
+    );
+    // the original is left untouched
+    assert_eq!(
+        decode(&options.serialize().unwrap()),
+        vec![
+            ("jql".to_owned(), "project = TEST".to_owned()),
+            ("startAt".to_owned(), "0".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn with_helpers_override_single_params() {
+    let options = SearchOptions::builder()
+        .jql("project = TEST")
+        .fields(vec!["summary"])
+        .start_at(0)
+        .build();
+
+    let next = options
+        .clone()
+        .with_start_at(100)
+        .with_max_results(50)
+        .with_jql("project = OTHER");
+
+    assert_eq!(
+        next,
+        SearchOptions::builder()
+            .jql("project = OTHER")
+            .fields(vec!["summary"])
+            .start_at(100)
+            .max_results(50)
+            .build()
+    );
+    assert_eq!(
+        options.clone().with_start_at(100),
+        options.as_builder().start_at(100).build()
+    );
+}
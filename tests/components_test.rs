@@ -0,0 +1,132 @@
+extern crate gouqi;
+extern crate mockito;
+extern crate serde_json;
+
+use gouqi::*;
+use mockito::{Matcher, Server};
+use serde_json::json;
+
+const COMPONENT: &str = r#"{
+    "self": "http://jira/rest/api/2/component/10000",
+    "id": "10000",
+    "name": "Backend",
+    "description": "Server side",
+    "project": "TEST",
+    "assigneeType": "COMPONENT_LEAD",
+    "lead": {
+        "self": "http://jira/rest/api/2/user?accountId=5b10a2844c20165700ede21g",
+        "accountId": "5b10a2844c20165700ede21g",
+        "displayName": "Ann",
+        "active": true
+    }
+}"#;
+
+#[test]
+fn get_component() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/rest/api/latest/component/10000")
+        .with_body(COMPONENT)
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let component = jira.components().get("10000").unwrap();
+
+    mock.assert();
+    assert_eq!(component.name, "Backend");
+    assert_eq!(component.description.as_deref(), Some("Server side"));
+    assert_eq!(component.project.as_deref(), Some("TEST"));
+    assert_eq!(component.assignee_type.as_deref(), Some("COMPONENT_LEAD"));
+    assert_eq!(
+        component.lead.unwrap().account_id.as_deref(),
+        Some("5b10a2844c20165700ede21g")
+    );
+}
+
+#[test]
+fn create_component_with_cloud_lead() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("POST", "/rest/api/latest/component")
+        .match_body(Matcher::Json(json!({
+            "name": "Backend",
+            "description": "Server side",
+            "project": "TEST",
+            "leadAccountId": "5b10a2844c20165700ede21g",
+            "assigneeType": "COMPONENT_LEAD"
+        })))
+        .with_status(201)
+        .with_body(COMPONENT)
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let component = jira
+        .components()
+        .create(CreateComponent {
+            name: "Backend".to_owned(),
+            description: Some("Server side".to_owned()),
+            project: "TEST".to_owned(),
+            lead_account_id: Some("5b10a2844c20165700ede21g".to_owned()),
+            assignee_type: Some("COMPONENT_LEAD".to_owned()),
+            ..Default::default()
+        })
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(component.id, "10000");
+}
+
+#[test]
+fn edit_component_with_server_lead() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("PUT", "/rest/api/latest/component/10000")
+        .match_body(Matcher::Json(json!({
+            "name": "Backend",
+            "description": null,
+            "project": "TEST",
+            "leadUserName": "ann"
+        })))
+        .with_body(COMPONENT)
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let component = jira
+        .components()
+        .edit(
+            "10000",
+            CreateComponent {
+                name: "Backend".to_owned(),
+                project: "TEST".to_owned(),
+                lead_user_name: Some("ann".to_owned()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(component.name, "Backend");
+}
+
+#[test]
+fn delete_component() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("DELETE", "/rest/api/latest/component/10000")
+        .with_status(204)
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    jira.components().delete("10000").unwrap();
+
+    mock.assert();
+}
+
+#[test]
+fn issue_component_serializes_minimal() {
+    let component: Component = serde_json::from_str(r#"{"id": "1", "name": "Backend"}"#).unwrap();
+    assert_eq!(
+        serde_json::to_value(component).unwrap(),
+        json!({ "id": "1", "name": "Backend" })
+    );
+}
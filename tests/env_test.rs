@@ -0,0 +1,137 @@
+extern crate gouqi;
+extern crate mockito;
+
+use gouqi::*;
+use std::env;
+use std::sync::{Mutex, MutexGuard};
+
+const VARS: [&str; 6] = [
+    "JIRA_URL",
+    "JIRA_HOST",
+    "JIRA_USER",
+    "JIRA_TOKEN",
+    "JIRA_PAT",
+    "JIRA_COOKIE",
+];
+
+// tests run in parallel but share the process environment
+static ENV: Mutex<()> = Mutex::new(());
+
+/// Sets the given variables, clearing the other Jira ones, and restores them on drop
+struct EnvScope {
+    saved: Vec<(&'static str, Option<String>)>,
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl EnvScope {
+    fn new(vars: &[(&str, &str)]) -> EnvScope {
+        let lock = ENV.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let saved = VARS
+            .iter()
+            .map(|name| (*name, env::var(name).ok()))
+            .collect();
+        for name in VARS {
+            env::remove_var(name);
+        }
+        for (name, value) in vars {
+            env::set_var(name, value);
+        }
+        EnvScope { saved, _lock: lock }
+    }
+}
+
+impl Drop for EnvScope {
+    fn drop(&mut self) {
+        for (name, value) in &self.saved {
+            match value {
+                Some(value) => env::set_var(name, value),
+                None => env::remove_var(name),
+            }
+        }
+    }
+}
+
+fn assert_invalid_input(result: Result<Jira>, expected: &str) {
+    match result {
+        Err(Error::InvalidInput(message)) => assert!(
+            message.contains(expected),
+            "expected '{expected}' in '{message}'"
+        ),
+        other => panic!("expected InvalidInput, got {other:?}"),
+    }
+}
+
+fn assert_header(vars: &[(&str, &str)], header: &str, value: &str) {
+    let mut server = mockito::Server::new();
+    let url = server.url();
+    let mock = server
+        .mock("GET", "/rest/api/latest/endpoint")
+        .match_header(header, value)
+        .create();
+
+    let mut vars = vars.to_vec();
+    vars.push(("JIRA_URL", &url));
+    let jira = {
+        let _scope = EnvScope::new(&vars);
+        Jira::from_env().unwrap()
+    };
+    jira.get::<EmptyResponse>("api", "/endpoint").unwrap();
+    mock.assert();
+}
+
+#[test]
+fn basic_credentials() {
+    assert_header(
+        &[("JIRA_USER", "user"), ("JIRA_TOKEN", "pwd")],
+        "authorization",
+        "Basic dXNlcjpwd2Q=",
+    );
+}
+
+#[test]
+fn personal_access_token() {
+    assert_header(&[("JIRA_PAT", "12345")], "authorization", "Bearer 12345");
+}
+
+#[test]
+fn cookie() {
+    assert_header(
+        &[("JIRA_COOKIE", "JSESSIONID=abc")],
+        "cookie",
+        "JSESSIONID=abc",
+    );
+}
+
+#[test]
+fn host_fallback() {
+    let _scope = EnvScope::new(&[("JIRA_HOST", "http://jira.local"), ("JIRA_PAT", "12345")]);
+    assert!(Jira::from_env().is_ok());
+}
+
+#[test]
+fn missing_host() {
+    let _scope = EnvScope::new(&[("JIRA_PAT", "12345")]);
+    assert_invalid_input(Jira::from_env(), "JIRA_URL");
+}
+
+#[test]
+fn missing_credentials() {
+    let _scope = EnvScope::new(&[("JIRA_URL", "http://jira.local"), ("JIRA_PAT", "")]);
+    assert_invalid_input(Jira::from_env(), "no credentials");
+}
+
+#[test]
+fn incomplete_basic_credentials() {
+    let _scope = EnvScope::new(&[("JIRA_URL", "http://jira.local"), ("JIRA_USER", "user")]);
+    assert_invalid_input(Jira::from_env(), "JIRA_USER is set without JIRA_TOKEN");
+}
+
+#[test]
+fn conflicting_credentials() {
+    let _scope = EnvScope::new(&[
+        ("JIRA_URL", "http://jira.local"),
+        ("JIRA_PAT", "12345"),
+        ("JIRA_COOKIE", "JSESSIONID=abc"),
+    ]);
+    assert_invalid_input(Jira::from_env(), "JIRA_PAT, JIRA_COOKIE");
+}
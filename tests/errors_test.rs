@@ -1,6 +1,11 @@
 extern crate gouqi;
 
-use gouqi::Error;
+use gouqi::{Credentials, EmptyResponse, Error, ErrorCategory, Errors, Jira};
+use reqwest::StatusCode;
+use std::collections::BTreeMap;
+use std::net::TcpListener;
+use std::time::Duration;
+
 #[test]
 fn test_error_display() {
     let error = Error::Unauthorized;
@@ -17,3 +22,208 @@ fn test_error_display() {
     let error = Error::NotFound;
     assert_eq!(format!("{}", error), "Jira request error: NotFound\n");
 }
+
+fn fault(code: u16) -> Error {
+    Error::Fault {
+        code: StatusCode::from_u16(code).unwrap(),
+        errors: Errors {
+            error_messages: vec![],
+            errors: BTreeMap::new(),
+            error: None,
+        },
+    }
+}
+
+#[test]
+fn test_error_retryable_faults() {
+    assert!(fault(429).is_retryable());
+    assert!(fault(502).is_retryable());
+    assert!(fault(503).is_retryable());
+    assert!(fault(504).is_retryable());
+    assert!(!fault(400).is_retryable());
+    assert!(!fault(403).is_retryable());
+    assert!(!fault(500).is_retryable());
+}
+
+#[test]
+fn test_error_not_retryable() {
+    assert!(!Error::Unauthorized.is_retryable());
+    assert!(!Error::MethodNotAllowed.is_retryable());
+    assert!(!Error::NotFound.is_retryable());
+    let serde_error = serde_json::from_str::<u32>("x").unwrap_err();
+    assert!(!Error::from(serde_error).is_retryable());
+}
+
+#[test]
+fn test_error_retryable_connect() {
+    // Bind and drop a listener to find a port nobody listens on
+    let port = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let jira = Jira::new(format!("http://127.0.0.1:{port}"), Credentials::Anonymous).unwrap();
+
+    let error = jira.get::<EmptyResponse>("api", "/endpoint").unwrap_err();
+    assert!(matches!(error, Error::Http(_)));
+    assert!(error.is_retryable());
+    assert_eq!(error.category(), ErrorCategory::Network);
+}
+
+#[test]
+fn test_error_retryable_timeout() {
+    // Accepts connections but never answers
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let host = format!("http://{}", listener.local_addr().unwrap());
+    let client = reqwest::blocking::Client::builder()
+        .timeout(Duration::from_millis(100))
+        .build()
+        .unwrap();
+    let jira = Jira::from_client(host, Credentials::Anonymous, client).unwrap();
+
+    let error = jira.get::<EmptyResponse>("api", "/endpoint").unwrap_err();
+    assert!(error.is_retryable());
+    drop(listener);
+}
+
+#[test]
+fn test_server_error_is_fault() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/rest/api/latest/endpoint")
+        .with_status(503)
+        .with_body("<html>Service Unavailable</html>")
+        .create();
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+
+    let error = jira.get::<EmptyResponse>("api", "/endpoint").unwrap_err();
+    mock.assert();
+    match error {
+        Error::Fault { code, ref errors } => {
+            assert_eq!(code, StatusCode::SERVICE_UNAVAILABLE);
+            assert_eq!(
+                errors.error_messages,
+                vec!["<html>Service Unavailable</html>"]
+            );
+        }
+        ref e => panic!("unexpected error {e:?}"),
+    }
+    assert!(error.is_retryable());
+}
+
+#[test]
+fn test_errors_lookups() {
+    let errors: Errors = serde_json::from_str(
+        r#"{
+            "errorMessages": ["Issue could not be created"],
+            "errors": { "summary": "You must specify a summary of the issue.", "priority": "Priority is invalid" }
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        errors.for_field("summary"),
+        Some("You must specify a summary of the issue.")
+    );
+    assert_eq!(errors.for_field("assignee"), None);
+    assert_eq!(
+        errors.all_messages(),
+        vec![
+            "Issue could not be created",
+            "priority: Priority is invalid",
+            "summary: You must specify a summary of the issue.",
+        ]
+    );
+}
+
+#[test]
+fn test_errors_single_error_message() {
+    let errors: Errors = serde_json::from_str(r#"{"error": "Rate limit exceeded"}"#).unwrap();
+
+    assert!(errors.error_messages.is_empty());
+    assert_eq!(errors.all_messages(), vec!["Rate limit exceeded"]);
+}
+
+#[test]
+fn test_fault_display() {
+    let mut field_errors = BTreeMap::new();
+    field_errors.insert("summary".to_owned(), "Summary is required".to_owned());
+    let error = Error::Fault {
+        code: StatusCode::BAD_REQUEST,
+        errors: Errors {
+            error_messages: vec!["Issue could not be created".to_owned()],
+            errors: field_errors,
+            error: None,
+        },
+    };
+
+    assert_eq!(
+        error.to_string(),
+        "Jira Client Error (400 Bad Request): Issue could not be created; summary: Summary is required\n"
+    );
+    assert_eq!(
+        std::error::Error::source(&error).map(|source| source.to_string()),
+        Some("Issue could not be created; summary: Summary is required".to_owned())
+    );
+}
+
+fn fault_with_body(code: u16, body: &str) -> Error {
+    Error::Fault {
+        code: StatusCode::from_u16(code).unwrap(),
+        errors: serde_json::from_str(body).unwrap(),
+    }
+}
+
+#[test]
+fn test_error_category_from_status() {
+    assert_eq!(
+        Error::Unauthorized.category(),
+        ErrorCategory::Authentication
+    );
+    assert_eq!(Error::NotFound.category(), ErrorCategory::NotFound);
+    assert_eq!(
+        Error::InvalidInput("no project key".to_owned()).category(),
+        ErrorCategory::Validation
+    );
+    assert_eq!(fault(401).category(), ErrorCategory::Authentication);
+    assert_eq!(fault(403).category(), ErrorCategory::Permission);
+    assert_eq!(fault(429).category(), ErrorCategory::RateLimited);
+    assert_eq!(fault(500).category(), ErrorCategory::Server);
+    assert_eq!(fault(503).category(), ErrorCategory::Server);
+    assert_eq!(fault(409).category(), ErrorCategory::Other);
+    let serde_error = serde_json::from_str::<u32>("x").unwrap_err();
+    assert_eq!(Error::from(serde_error).category(), ErrorCategory::Other);
+}
+
+#[test]
+fn test_error_category_from_messages() {
+    let permission = fault_with_body(
+        400,
+        r#"{"errorMessages": ["It seems that you have tried to perform an operation which is not permitted."], "errors": {}}"#,
+    );
+    assert_eq!(permission.category(), ErrorCategory::Permission);
+
+    let workflow = fault_with_body(
+        400,
+        r#"{"errorMessages": ["It seems that you have tried to perform a workflow operation (Resolve Issue) that is not valid for the current state of this issue (TEST-1)."], "errors": {}}"#,
+    );
+    assert_eq!(workflow.category(), ErrorCategory::Workflow);
+
+    let missing = fault_with_body(
+        400,
+        r#"{"errorMessages": [], "errors": {"project": "project 'NOPE' does not exist"}}"#,
+    );
+    assert_eq!(missing.category(), ErrorCategory::NotFound);
+
+    let validation = fault_with_body(
+        400,
+        r#"{"errorMessages": [], "errors": {"summary": "You must specify a summary of the issue."}}"#,
+    );
+    assert_eq!(validation.category(), ErrorCategory::Validation);
+
+    let invisible = fault_with_body(
+        404,
+        r#"{"errorMessages": ["Issue does not exist or you do not have permission to see it."], "errors": {}}"#,
+    );
+    assert_eq!(invisible.category(), ErrorCategory::NotFound);
+}
@@ -102,12 +102,12 @@ mod changelog_tests {
 }"#)
             .create();
 
-        let jira = Jira::new(&*url, gouqi::Credentials::Anonymous).unwrap();
+        let jira = Jira::new(url, gouqi::Credentials::Anonymous).unwrap();
         let issues = Issues::new(&jira);
 
         let result = issues.changelog("TEST-1");
         mock_server.assert();
-        assert_eq!(result.is_ok(), true);
+        assert!(result.is_ok());
         let changelog = result.unwrap();
         assert_eq!(changelog.histories.len(), 2);
         assert_eq!(
@@ -129,7 +129,7 @@ mod changelog_tests {
             .with_status(404)
             .create();
 
-        let jira = Jira::new(&*url, gouqi::Credentials::Anonymous).unwrap();
+        let jira = Jira::new(url, gouqi::Credentials::Anonymous).unwrap();
         let issues = Issues::new(&jira);
 
         let result = issues.changelog("NONEXISTENT-1");
@@ -148,7 +148,7 @@ mod changelog_tests {
             .with_status(500)
             .create();
 
-        let jira = Jira::new(&*url, gouqi::Credentials::Anonymous).unwrap();
+        let jira = Jira::new(url, gouqi::Credentials::Anonymous).unwrap();
         let issues = Issues::new(&jira);
 
         let result = issues.changelog("TEST-2");
@@ -157,4 +157,1599 @@ mod changelog_tests {
 
         mock_server.assert();
     }
+
+    fn history(id: u32, created: &str) -> String {
+        format!(
+            r#"{{"id": "{id}", "author": {{"self": "http://jira/rest/api/2/user?username=fred", "displayName": "Fred", "active": true}}, "created": "{created}", "items": []}}"#
+        )
+    }
+
+    #[test]
+    fn test_changelog_since_pages_and_filters() {
+        let mut server = Server::new();
+        let first = server
+            .mock("GET", "/rest/api/latest/issue/TEST-1/changelog")
+            .match_query(mockito::Matcher::UrlEncoded("startAt".into(), "0".into()))
+            .with_body(format!(
+                r#"{{"startAt": 0, "maxResults": 2, "total": 4, "isLast": false, "values": [{}, {}]}}"#,
+                history(1, "2024-01-01T09:00:00.000+0000"),
+                history(2, "2024-01-02T09:00:00.000+0000"),
+            ))
+            .create();
+        let second = server
+            .mock("GET", "/rest/api/latest/issue/TEST-1/changelog")
+            .match_query(mockito::Matcher::UrlEncoded("startAt".into(), "2".into()))
+            .with_body(format!(
+                r#"{{"startAt": 2, "maxResults": 2, "total": 4, "isLast": true, "values": [{}, {}]}}"#,
+                history(3, "2024-01-03T09:00:00.000+0000"),
+                history(4, "yesterday"),
+            ))
+            .create();
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+        // 2024-01-01T12:00:00Z
+        let since = time::OffsetDateTime::from_unix_timestamp(1_704_110_400).unwrap();
+        let histories = Issues::new(&jira).changelog_since("TEST-1", since).unwrap();
+
+        first.assert();
+        second.assert();
+        let created = histories
+            .iter()
+            .map(|history| history.created.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            created,
+            vec![
+                "2024-01-02T09:00:00.000+0000",
+                "2024-01-03T09:00:00.000+0000",
+                "yesterday"
+            ]
+        );
+    }
+}
+
+mod create_tests {
+    use super::*;
+    use gouqi::Jira;
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    #[test]
+    fn create_minimal_issue() {
+        let mut server = Server::new();
+        let url = &server.url();
+        let mock_server = server
+            .mock("POST", "/rest/api/latest/issue")
+            .match_body(Matcher::Json(json!({
+                "fields": {
+                    "project": { "key": "TEST" },
+                    "issuetype": { "name": "Task" },
+                    "summary": "Minimal issue"
+                }
+            })))
+            .with_status(201)
+            .with_body(
+                r#"{"id": "10000", "key": "TEST-1", "self": "http://jira/rest/api/2/issue/10000"}"#,
+            )
+            .create();
+
+        let jira = Jira::new(url, gouqi::Credentials::Anonymous).unwrap();
+        let issues = Issues::new(&jira);
+
+        let data = CreateCustomIssue::builder("TEST", "Task", "Minimal issue")
+            .build()
+            .unwrap();
+        let response = issues.create_from_custom_issue(data).unwrap();
+
+        mock_server.assert();
+        assert_eq!(response.key, "TEST-1");
+    }
+
+    #[test]
+    fn create_issue_builder_optional_fields() {
+        let data = CreateCustomIssue::builder("TEST", "Bug", "Broken")
+            .description("It is broken")
+            .priority("High")
+            .labels(vec!["backend"])
+            .field("customfield_10001", 3)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&data).unwrap(),
+            json!({
+                "fields": {
+                    "project": { "key": "TEST" },
+                    "issuetype": { "name": "Bug" },
+                    "summary": "Broken",
+                    "description": "It is broken",
+                    "priority": { "name": "High" },
+                    "labels": ["backend"],
+                    "customfield_10001": 3
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn create_issue_builder_reports_unserializable_fields() {
+        let mut map = std::collections::BTreeMap::new();
+        map.insert((1, 2), "not a string key");
+        let result = CreateCustomIssue::builder("TEST", "Bug", "Broken")
+            .field("customfield_10001", map)
+            .build();
+
+        match result {
+            Err(gouqi::Error::InvalidInput(message)) => {
+                assert!(message.starts_with("field customfield_10001 could not be serialized"))
+            }
+            other => panic!("expected invalid input, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn create_subtask_in_parent_project() {
+        let mut server = Server::new();
+        let url = &server.url();
+        let parent = server
+            .mock("GET", "/rest/api/latest/issue/TEST-1")
+            .match_query(Matcher::UrlEncoded("fields".into(), "project".into()))
+            .with_body(
+                r#"{
+                    "self": "http://jira/rest/api/2/issue/10000",
+                    "id": "10000",
+                    "key": "TEST-1",
+                    "fields": { "project": { "id": "10", "key": "TEST", "name": "Test", "self": "http://jira/rest/api/2/project/10" } }
+                }"#,
+            )
+            .create();
+        let create = server
+            .mock("POST", "/rest/api/latest/issue")
+            .match_body(Matcher::Json(json!({
+                "fields": {
+                    "project": { "key": "TEST" },
+                    "issuetype": { "name": "Sub-task" },
+                    "summary": "Write tests",
+                    "parent": { "key": "TEST-1" },
+                    "description": "Cover the edge cases",
+                    "labels": ["qa"]
+                }
+            })))
+            .with_status(201)
+            .with_body(
+                r#"{"id": "10001", "key": "TEST-2", "self": "http://jira/rest/api/2/issue/10001"}"#,
+            )
+            .create();
+
+        let jira = Jira::new(url, gouqi::Credentials::Anonymous).unwrap();
+        let mut input = SubtaskInput::new("Write tests");
+        input.description = Some("Cover the edge cases".to_owned());
+        input.fields.insert("labels".to_owned(), json!(["qa"]));
+        let response = Issues::new(&jira).create_subtask("TEST-1", input).unwrap();
+
+        parent.assert();
+        create.assert();
+        assert_eq!(response.key, "TEST-2");
+    }
+
+    #[test]
+    fn create_subtask_requests_the_project_despite_default_fields() {
+        let mut server = Server::new();
+        let parent = server
+            .mock("GET", "/rest/api/latest/issue/TEST-1")
+            .match_query(Matcher::UrlEncoded("fields".into(), "project".into()))
+            .with_body(
+                r#"{
+                    "self": "http://jira/rest/api/2/issue/10000",
+                    "id": "10000",
+                    "key": "TEST-1",
+                    "fields": { "project": { "id": "10", "key": "TEST", "name": "Test" } }
+                }"#,
+            )
+            .create();
+        let create = server
+            .mock("POST", "/rest/api/latest/issue")
+            .match_body(Matcher::PartialJson(json!({
+                "fields": { "project": { "key": "TEST" }, "parent": { "key": "TEST-1" } }
+            })))
+            .with_status(201)
+            .with_body(
+                r#"{"id": "10001", "key": "TEST-2", "self": "http://jira/rest/api/2/issue/10001"}"#,
+            )
+            .create();
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous)
+            .unwrap()
+            .with_default_issue_fields(vec!["key", "summary", "status"]);
+        Issues::new(&jira)
+            .create_subtask("TEST-1", SubtaskInput::new("Write tests"))
+            .unwrap();
+
+        parent.assert();
+        create.assert();
+    }
+
+    #[test]
+    fn create_subtask_of_parent_without_project_fails() {
+        let mut server = Server::new();
+        let parent = server
+            .mock("GET", "/rest/api/latest/issue/TEST-1")
+            .match_query(Matcher::Any)
+            .with_body(
+                r#"{"self": "http://jira/rest/api/2/issue/10000", "id": "10000", "key": "TEST-1", "fields": {}}"#,
+            )
+            .create();
+        let create = server
+            .mock("POST", "/rest/api/latest/issue")
+            .expect(0)
+            .create();
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+        let result = Issues::new(&jira).create_subtask("TEST-1", SubtaskInput::new("Write tests"));
+
+        parent.assert();
+        create.assert();
+        match result {
+            Err(gouqi::Error::InvalidInput(message)) => {
+                assert_eq!(message, "parent issue TEST-1 has no project")
+            }
+            other => panic!("expected invalid input, got {other:?}"),
+        }
+    }
+}
+
+mod clone_tests {
+    use super::*;
+    use gouqi::Jira;
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    #[test]
+    fn clone_issue_with_links_and_attachments() {
+        let mut server = Server::new();
+        let url = server.url();
+        let source = json!({
+            "self": format!("{url}/rest/api/2/issue/10000"),
+            "id": "10000",
+            "key": "SRC-1",
+            "fields": {
+                "summary": "Original",
+                "description": "Some text",
+                "labels": ["a"],
+                "project": { "id": "1", "key": "SRC", "name": "Source" },
+                "issuetype": {
+                    "self": format!("{url}/rest/api/2/issuetype/1"),
+                    "id": "1",
+                    "description": "",
+                    "iconUrl": "",
+                    "name": "Task",
+                    "subtask": false
+                },
+                "issuelinks": [{
+                    "id": "1",
+                    "self": format!("{url}/rest/api/2/issueLink/1"),
+                    "type": {
+                        "id": "10000",
+                        "name": "Blocks",
+                        "inward": "is blocked by",
+                        "outward": "blocks",
+                        "self": format!("{url}/rest/api/2/issueLinkType/10000")
+                    },
+                    "outwardIssue": {
+                        "self": format!("{url}/rest/api/2/issue/10002"),
+                        "id": "10002",
+                        "key": "SRC-2",
+                        "fields": {}
+                    }
+                }],
+                "attachment": [{
+                    "id": "5",
+                    "self": format!("{url}/rest/api/2/attachment/5"),
+                    "filename": "notes.txt",
+                    "author": {
+                        "self": format!("{url}/rest/api/2/user?username=fred"),
+                        "displayName": "Fred",
+                        "active": true
+                    },
+                    "created": "2024-01-01T09:00:00.000+0000",
+                    "size": 5,
+                    "mimeType": "text/plain",
+                    "content": format!("{url}/secure/attachment/5/notes.txt")
+                }]
+            }
+        });
+
+        let get_source = server
+            .mock("GET", "/rest/api/latest/issue/SRC-1")
+            .match_query(Matcher::Any)
+            .with_body(source.to_string())
+            .create();
+        let create = server
+            .mock("POST", "/rest/api/latest/issue")
+            .match_body(Matcher::Json(json!({
+                "fields": {
+                    "project": { "key": "DST" },
+                    "issuetype": { "name": "Task" },
+                    "summary": "CLONE - Original",
+                    "description": "Some text",
+                    "labels": ["a"]
+                }
+            })))
+            .with_status(201)
+            .with_body(
+                r#"{"id": "20000", "key": "DST-1", "self": "http://jira/rest/api/2/issue/20000"}"#,
+            )
+            .create();
+        let link = server
+            .mock("POST", "/rest/api/latest/issueLink")
+            .match_body(Matcher::Json(json!({
+                "type": { "name": "Blocks" },
+                "inwardIssue": { "key": "DST-1" },
+                "outwardIssue": { "key": "SRC-2" }
+            })))
+            .with_status(201)
+            .create();
+        let download = server
+            .mock("GET", "/secure/attachment/5/notes.txt")
+            .with_body("hello")
+            .create();
+        let upload = server
+            .mock("POST", "/rest/api/latest/issue/DST-1/attachments")
+            .match_header("X-Atlassian-Token", "no-check")
+            .match_body(Matcher::Regex("filename=\"notes.txt\"".to_owned()))
+            .with_body("[]")
+            .create();
+
+        let jira = Jira::new(url, gouqi::Credentials::Anonymous).unwrap();
+        let created = jira
+            .issues()
+            .clone_issue(
+                "SRC-1",
+                CloneOptions {
+                    summary_prefix: Some("CLONE - ".to_owned()),
+                    copy_attachments: true,
+                    copy_links: true,
+                    target_project: Some("DST".to_owned()),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(created.key, "DST-1");
+        get_source.assert();
+        create.assert();
+        link.assert();
+        download.assert();
+        upload.assert();
+    }
+
+    #[test]
+    fn clone_issue_requests_the_copied_fields_despite_default_fields() {
+        let mut server = Server::new();
+        let get_source = server
+            .mock("GET", "/rest/api/latest/issue/SRC-1")
+            .match_query(Matcher::UrlEncoded(
+                "fields".into(),
+                "project,issuetype,summary,description,environment,labels,priority,components,issuelinks,attachment".into(),
+            ))
+            .with_body(
+                json!({
+                    "self": "http://jira/rest/api/2/issue/10000",
+                    "id": "10000",
+                    "key": "SRC-1",
+                    "fields": {
+                        "summary": "Original",
+                        "description": "Some text",
+                        "labels": ["a"],
+                        "project": { "id": "1", "key": "SRC", "name": "Source" },
+                        "issuetype": {
+                            "self": "http://jira/rest/api/2/issuetype/1",
+                            "id": "1",
+                            "iconUrl": "",
+                            "name": "Task",
+                            "subtask": false
+                        }
+                    }
+                })
+                .to_string(),
+            )
+            .create();
+        let create = server
+            .mock("POST", "/rest/api/latest/issue")
+            .match_body(Matcher::Json(json!({
+                "fields": {
+                    "project": { "key": "SRC" },
+                    "issuetype": { "name": "Task" },
+                    "summary": "Original",
+                    "description": "Some text",
+                    "labels": ["a"]
+                }
+            })))
+            .with_status(201)
+            .with_body(
+                r#"{"id": "10001", "key": "SRC-2", "self": "http://jira/rest/api/2/issue/10001"}"#,
+            )
+            .create();
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous)
+            .unwrap()
+            .with_default_issue_fields(vec!["key", "summary", "status"]);
+        let created = jira
+            .issues()
+            .clone_issue("SRC-1", CloneOptions::default())
+            .unwrap();
+
+        get_source.assert();
+        create.assert();
+        assert_eq!(created.key, "SRC-2");
+    }
+
+    fn clone_error(fields: serde_json::Value, options: CloneOptions) -> String {
+        let mut server = Server::new();
+        let get_source = server
+            .mock("GET", "/rest/api/latest/issue/SRC-1")
+            .match_query(Matcher::Any)
+            .with_body(
+                json!({
+                    "self": "http://jira/rest/api/2/issue/10000",
+                    "id": "10000",
+                    "key": "SRC-1",
+                    "fields": fields
+                })
+                .to_string(),
+            )
+            .create();
+        let create = server
+            .mock("POST", "/rest/api/latest/issue")
+            .expect(0)
+            .create();
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+        let result = jira.issues().clone_issue("SRC-1", options);
+
+        get_source.assert();
+        create.assert();
+        match result {
+            Err(gouqi::Error::InvalidInput(message)) => message,
+            other => panic!("expected invalid input, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn clone_issue_without_required_source_values_fails() {
+        let task = json!({
+            "self": "http://jira/rest/api/2/issuetype/1",
+            "id": "1",
+            "iconUrl": "",
+            "name": "Task",
+            "subtask": false
+        });
+        let project = json!({ "id": "1", "key": "SRC", "name": "Source" });
+
+        assert_eq!(
+            clone_error(
+                json!({ "summary": "Original", "issuetype": task }),
+                CloneOptions::default()
+            ),
+            "source issue SRC-1 has no project"
+        );
+        assert_eq!(
+            clone_error(
+                json!({ "summary": "Original", "project": project }),
+                CloneOptions {
+                    target_project: Some("DST".to_owned()),
+                    ..CloneOptions::default()
+                }
+            ),
+            "source issue SRC-1 has no issue type"
+        );
+        assert_eq!(
+            clone_error(
+                json!({ "project": project, "issuetype": task }),
+                CloneOptions {
+                    summary_prefix: Some("CLONE - ".to_owned()),
+                    ..CloneOptions::default()
+                }
+            ),
+            "source issue SRC-1 has no summary"
+        );
+    }
+}
+
+mod comment_tests {
+    use super::*;
+    use gouqi::{Jira, Visibility};
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    #[test]
+    fn add_restricted_comment() {
+        let mut server = Server::new();
+        let url = &server.url();
+        let mock_server = server
+            .mock("POST", "/rest/api/latest/issue/TEST-1/comment")
+            .match_body(Matcher::Json(json!({
+                "body": "Internal note",
+                "visibility": { "type": "role", "value": "Administrators" }
+            })))
+            .with_status(201)
+            .with_body(
+                r#"{
+                    "self": "http://jira/rest/api/2/issue/10000/comment/10001",
+                    "id": "10001",
+                    "body": "Internal note",
+                    "visibility": { "type": "role", "value": "Administrators" }
+                }"#,
+            )
+            .create();
+
+        let jira = Jira::new(url, gouqi::Credentials::Anonymous).unwrap();
+        let comment = Issues::new(&jira)
+            .comment(
+                "TEST-1",
+                AddComment::new("Internal note")
+                    .with_visibility(Visibility::role("Administrators")),
+            )
+            .unwrap();
+
+        mock_server.assert();
+        assert!(comment.is_restricted());
+    }
+
+    #[test]
+    fn add_unrestricted_comment_omits_visibility() {
+        assert_eq!(
+            serde_json::to_value(AddComment::new("Hello")).unwrap(),
+            json!({ "body": "Hello" })
+        );
+    }
+}
+
+mod bulk_create_tests {
+    use super::*;
+    use gouqi::Jira;
+    use mockito::{Matcher, Server};
+    use serde::Serialize;
+    use serde_json::json;
+
+    #[derive(Serialize)]
+    struct Key {
+        key: String,
+    }
+
+    #[derive(Serialize)]
+    struct Name {
+        name: String,
+    }
+
+    #[derive(Serialize)]
+    struct StoryFields {
+        project: Key,
+        issuetype: Name,
+        summary: String,
+        #[serde(rename = "customfield_10002")]
+        story_points: u32,
+    }
+
+    fn story(summary: &str, story_points: u32) -> CreateCustomIssue<StoryFields> {
+        CreateCustomIssue {
+            fields: StoryFields {
+                project: Key {
+                    key: "TEST".to_owned(),
+                },
+                issuetype: Name {
+                    name: "Story".to_owned(),
+                },
+                summary: summary.to_owned(),
+                story_points,
+            },
+        }
+    }
+
+    #[test]
+    fn bulk_create_typed_issues() {
+        let mut server = Server::new();
+        let url = &server.url();
+        let mock_server = server
+            .mock("POST", "/rest/api/latest/issue/bulk")
+            .match_body(Matcher::Json(json!({
+                "issueUpdates": [
+                    { "fields": {
+                        "project": { "key": "TEST" },
+                        "issuetype": { "name": "Story" },
+                        "summary": "First",
+                        "customfield_10002": 3
+                    } },
+                    { "fields": {
+                        "project": { "key": "TEST" },
+                        "issuetype": { "name": "Story" },
+                        "summary": "Second",
+                        "customfield_10002": 5
+                    } }
+                ]
+            })))
+            .with_status(201)
+            .with_body(
+                r#"{
+                    "issues": [
+                        { "id": "10000", "key": "TEST-1", "self": "http://jira/rest/api/2/issue/10000" }
+                    ],
+                    "errors": [
+                        {
+                            "status": 400,
+                            "elementErrors": {
+                                "errorMessages": [],
+                                "errors": { "customfield_10002": "Story points must be at most 3" }
+                            },
+                            "failedElementNumber": 1
+                        }
+                    ]
+                }"#,
+            )
+            .create();
+
+        let jira = Jira::new(url, gouqi::Credentials::Anonymous).unwrap();
+        let response = Issues::new(&jira)
+            .bulk_create_custom(vec![story("First", 3), story("Second", 5)])
+            .unwrap();
+
+        mock_server.assert();
+        assert_eq!(response.issues.len(), 1);
+        assert_eq!(response.issues[0].key, "TEST-1");
+        assert_eq!(response.errors.len(), 1);
+        assert_eq!(response.errors[0].failed_element_number, 1);
+        assert!(response.errors[0]
+            .element_errors
+            .errors
+            .contains_key("customfield_10002"));
+    }
+}
+
+mod epic_tests {
+    use super::*;
+    use gouqi::Jira;
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    #[test]
+    fn add_issues_to_epic_in_chunks() {
+        let mut server = Server::new();
+        let url = &server.url();
+        let keys = (1..=60).map(|n| format!("TEST-{n}")).collect::<Vec<_>>();
+        let first = server
+            .mock("POST", "/rest/agile/latest/epic/TEST-100/issue")
+            .match_body(Matcher::Json(json!({ "issues": keys[..50] })))
+            .with_status(204)
+            .create();
+        let second = server
+            .mock("POST", "/rest/agile/latest/epic/TEST-100/issue")
+            .match_body(Matcher::Json(json!({ "issues": keys[50..] })))
+            .with_status(204)
+            .create();
+
+        let jira = Jira::new(url, gouqi::Credentials::Anonymous).unwrap();
+        Issues::new(&jira)
+            .add_issues_to_epic("TEST-100", keys)
+            .unwrap();
+
+        first.assert();
+        second.assert();
+    }
+
+    #[test]
+    fn remove_issues_from_epic() {
+        let mut server = Server::new();
+        let url = &server.url();
+        let mock_server = server
+            .mock("POST", "/rest/agile/latest/epic/none/issue")
+            .match_body(Matcher::Json(json!({ "issues": ["TEST-1", "TEST-2"] })))
+            .with_status(204)
+            .create();
+
+        let jira = Jira::new(url, gouqi::Credentials::Anonymous).unwrap();
+        Issues::new(&jira)
+            .remove_issues_from_epic(vec!["TEST-1".to_owned(), "TEST-2".to_owned()])
+            .unwrap();
+
+        mock_server.assert();
+    }
+
+    #[test]
+    fn empty_epic_move_sends_nothing() {
+        let mut server = Server::new();
+        let url = &server.url();
+        let mock_server = server
+            .mock("POST", "/rest/agile/latest/epic/none/issue")
+            .expect(0)
+            .create();
+
+        let jira = Jira::new(url, gouqi::Credentials::Anonymous).unwrap();
+        Issues::new(&jira).remove_issues_from_epic(vec![]).unwrap();
+
+        mock_server.assert();
+    }
+}
+
+mod iter_tests {
+    use super::*;
+    use gouqi::{Board, Jira, SearchOptions};
+    use mockito::{Matcher, Mock, Server};
+
+    fn board() -> Board {
+        serde_json::from_str(
+            r#"{"id": 1, "self": "http://jira/rest/agile/1.0/board/1", "name": "TEST", "type": "scrum"}"#,
+        )
+        .unwrap()
+    }
+
+    fn page_mock(server: &mut Server, start_at: u64, count: u64, total: u64) -> Mock {
+        let issues = (start_at..start_at + count)
+            .map(|n| {
+                format!(
+                    r#"{{"self": "http://jira/rest/api/2/issue/{n}", "id": "{n}", "key": "TEST-{n}", "fields": {{}}}}"#
+                )
+            })
+            .collect::<Vec<_>>();
+        server
+            .mock("GET", "/rest/agile/latest/board/1/issue")
+            .match_query(Matcher::UrlEncoded("startAt".into(), start_at.to_string()))
+            .with_body(format!(
+                r#"{{"total": {total}, "maxResults": 2, "startAt": {start_at}, "issues": [{}]}}"#,
+                issues.join(",")
+            ))
+            .create()
+    }
+
+    fn iterate(server: &Server) -> Vec<String> {
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+        let board = board();
+        let options = SearchOptions::builder().start_at(0).max_results(2).build();
+        Issues::new(&jira)
+            .iter(&board, &options)
+            .unwrap()
+            .map(|issue| issue.key)
+            .collect()
+    }
+
+    #[test]
+    fn exactly_one_page() {
+        let mut server = Server::new();
+        let first = page_mock(&mut server, 0, 2, 2);
+        let extra = page_mock(&mut server, 2, 0, 2).expect(0);
+
+        assert_eq!(iterate(&server), vec!["TEST-0", "TEST-1"]);
+        first.assert();
+        extra.assert();
+    }
+
+    #[test]
+    fn exactly_two_pages() {
+        let mut server = Server::new();
+        let first = page_mock(&mut server, 0, 2, 4);
+        let second = page_mock(&mut server, 2, 2, 4);
+        let extra = page_mock(&mut server, 4, 0, 4).expect(0);
+
+        assert_eq!(
+            iterate(&server),
+            vec!["TEST-0", "TEST-1", "TEST-2", "TEST-3"]
+        );
+        first.assert();
+        second.assert();
+        extra.assert();
+    }
+
+    #[test]
+    fn empty_result_set() {
+        let mut server = Server::new();
+        let first = page_mock(&mut server, 0, 0, 0);
+        let extra = page_mock(&mut server, 2, 0, 0).expect(0);
+
+        assert!(iterate(&server).is_empty());
+        first.assert();
+        extra.assert();
+    }
+}
+
+mod get_tests {
+    use super::*;
+    use gouqi::{Jira, SearchOptions};
+    use mockito::{Matcher, Server};
+
+    #[test]
+    fn get_with_excluded_fields() {
+        let mut server = Server::new();
+        let mock_server = server
+            .mock("GET", "/rest/api/latest/issue/TEST-1")
+            .match_query(Matcher::Exact("fields=*all%2C-comment".into()))
+            .with_body(
+                r#"{"self": "http://jira/rest/api/2/issue/10000", "id": "10000", "key": "TEST-1", "fields": {"summary": "Hello"}}"#,
+            )
+            .create();
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+        let issue = Issues::new(&jira)
+            .get_with_options(
+                "TEST-1",
+                &SearchOptions::builder()
+                    .exclude_fields(vec!["comment"])
+                    .build(),
+            )
+            .unwrap();
+
+        mock_server.assert();
+        assert_eq!(issue.summary().as_deref(), Some("Hello"));
+        assert!(issue.was_requested("status"));
+        assert!(!issue.was_requested("comment"));
+    }
+
+    #[test]
+    fn get_with_fields_tells_unrequested_fields_from_empty_ones() {
+        let mut server = Server::new();
+        let mock_server = server
+            .mock("GET", "/rest/api/latest/issue/TEST-1")
+            .match_query(Matcher::Exact("fields=summary%2Cassignee".into()))
+            .with_body(
+                r#"{"self": "http://jira/rest/api/2/issue/10000", "id": "10000", "key": "TEST-1", "fields": {"summary": "Hello", "assignee": null}}"#,
+            )
+            .create();
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+        let issue = Issues::new(&jira)
+            .get_with_options(
+                "TEST-1",
+                &SearchOptions::builder()
+                    .fields(vec!["summary", "assignee"])
+                    .build(),
+            )
+            .unwrap();
+
+        mock_server.assert();
+        assert!(issue.assignee().is_none());
+        assert!(issue.was_requested("assignee"));
+        assert!(issue.status().is_none());
+        assert!(!issue.was_requested("status"));
+    }
+
+    #[test]
+    fn get_with_navigable_fields_leaves_out_non_navigable_ones() {
+        let mut server = Server::new();
+        let navigable = server
+            .mock("GET", "/rest/api/latest/issue/TEST-1")
+            .match_query(Matcher::Exact("fields=*navigable".into()))
+            .with_body(
+                r#"{"self": "http://jira/rest/api/2/issue/10000", "id": "10000", "key": "TEST-1", "fields": {"summary": "Hello"}}"#,
+            )
+            .create();
+        let all = server
+            .mock("GET", "/rest/api/latest/issue/TEST-1")
+            .match_query(Matcher::Missing)
+            .with_body(
+                r#"{"self": "http://jira/rest/api/2/issue/10000", "id": "10000", "key": "TEST-1", "fields": {"summary": "Hello", "comment": {"comments": []}}}"#,
+            )
+            .create();
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+        let issues = Issues::new(&jira);
+        let issue = issues
+            .get_with_options(
+                "TEST-1",
+                &SearchOptions::builder().fields(vec!["*navigable"]).build(),
+            )
+            .unwrap();
+        assert!(issue.was_requested("summary"));
+        assert!(issue.was_requested("customfield_10001"));
+        assert!(!issue.was_requested("comment"));
+        assert!(!issue.was_requested("worklog"));
+        assert!(!issue.was_requested("attachment"));
+
+        let issue = issues.get("TEST-1").unwrap();
+        assert!(issue.was_requested("comment"));
+
+        navigable.assert();
+        all.assert();
+    }
+
+    #[test]
+    fn embedded_issues_report_no_field_as_requested() {
+        let mut server = Server::new();
+        let mock_server = server
+            .mock("GET", "/rest/api/latest/issue/TEST-2")
+            .with_body(
+                r#"{
+                    "self": "http://jira/rest/api/2/issue/10001",
+                    "id": "10001",
+                    "key": "TEST-2",
+                    "fields": {
+                        "summary": "Parent of many",
+                        "parent": {"self": "http://jira/rest/api/2/issue/10000", "id": "10000", "key": "TEST-1", "fields": {"summary": "Epic"}},
+                        "subtasks": [{"self": "http://jira/rest/api/2/issue/10002", "id": "10002", "key": "TEST-3", "fields": {"summary": "Child"}}],
+                        "issuelinks": [{
+                            "id": "1",
+                            "self": "http://jira/rest/api/2/issueLink/1",
+                            "type": {"id": "10000", "name": "Blocks", "inward": "is blocked by", "outward": "blocks", "self": "http://jira/rest/api/2/issueLinkType/10000"},
+                            "inwardIssue": {"self": "http://jira/rest/api/2/issue/10003", "id": "10003", "key": "TEST-4", "fields": {"summary": "Blocker"}}
+                        }]
+                    }
+                }"#,
+            )
+            .create();
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+        let issue = Issues::new(&jira).get("TEST-2").unwrap();
+
+        mock_server.assert();
+        assert!(issue.was_requested("description"));
+        let parent = issue.parent().unwrap();
+        assert!(parent.description().is_none());
+        assert!(!parent.was_requested("description"));
+        assert!(!issue.subtasks()[0].was_requested("description"));
+        let links = issue.links().unwrap().unwrap();
+        let linked = links[0].inward_issue.as_ref().unwrap();
+        assert!(!linked.was_requested("description"));
+    }
+
+    #[test]
+    fn try_get_returns_existing_issue() {
+        let mut server = Server::new();
+        let mock_server = server
+            .mock("GET", "/rest/api/latest/issue/TEST-1")
+            .with_body(
+                r#"{"self": "http://jira/rest/api/2/issue/10000", "id": "10000", "key": "TEST-1", "fields": {"summary": "Hello"}}"#,
+            )
+            .create();
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+        let issue = Issues::new(&jira)
+            .try_get("TEST-1", &SearchOptions::default())
+            .unwrap();
+
+        mock_server.assert();
+        assert_eq!(issue.map(|issue| issue.key).as_deref(), Some("TEST-1"));
+    }
+
+    #[test]
+    fn try_get_returns_none_for_missing_issue() {
+        let mut server = Server::new();
+        let mock_server = server
+            .mock("GET", "/rest/api/latest/issue/TEST-404")
+            .with_status(404)
+            .with_body(
+                r#"{"errorMessages": ["Issue does not exist or you do not have permission to see it."], "errors": {}}"#,
+            )
+            .create();
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+        let issue = Issues::new(&jira)
+            .try_get("TEST-404", &SearchOptions::default())
+            .unwrap();
+
+        mock_server.assert();
+        assert!(issue.is_none());
+    }
+
+    #[test]
+    fn try_get_fails_when_forbidden() {
+        let mut server = Server::new();
+        let mock_server = server
+            .mock("GET", "/rest/api/latest/issue/TEST-1")
+            .with_status(403)
+            .with_body(r#"{"errorMessages": ["Forbidden"], "errors": {}}"#)
+            .create();
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+        let result = Issues::new(&jira).try_get("TEST-1", &SearchOptions::default());
+
+        mock_server.assert();
+        match result {
+            Err(gouqi::Error::Fault { code, .. }) => assert_eq!(code.as_u16(), 403),
+            other => panic!("expected a fault, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn list_attachments_fetches_only_the_attachment_field() {
+        let mut server = Server::new();
+        let mock_server = server
+            .mock("GET", "/rest/api/latest/issue/TEST-1")
+            .match_query(Matcher::Exact("fields=attachment".into()))
+            .with_body(
+                r#"{"self": "http://jira/rest/api/2/issue/10000", "id": "10000", "key": "TEST-1", "fields": {"attachment": [{
+                    "id": "10001",
+                    "self": "http://jira/rest/api/2/attachment/10001",
+                    "filename": "report.pdf",
+                    "author": {"self": "http://jira/rest/api/2/user?username=fred", "displayName": "Fred", "active": true},
+                    "created": "2024-01-01T09:00:00.000+0000",
+                    "size": 2048,
+                    "mimeType": "application/pdf",
+                    "content": "http://jira/secure/attachment/10001/report.pdf"
+                }]}}"#,
+            )
+            .create();
+
+        let jira = Jira::builder(server.url())
+            .default_issue_fields(vec!["summary"])
+            .build()
+            .unwrap();
+        let attachments = Issues::new(&jira).list_attachments("TEST-1").unwrap();
+
+        mock_server.assert();
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(attachments[0].filename, "report.pdf");
+        assert_eq!(attachments[0].size, 2048);
+    }
+
+    #[test]
+    fn list_attachments_of_issue_without_any() {
+        let mut server = Server::new();
+        let mock_server = server
+            .mock("GET", "/rest/api/latest/issue/TEST-1")
+            .match_query(Matcher::Exact("fields=attachment".into()))
+            .with_body(
+                r#"{"self": "http://jira/rest/api/2/issue/10000", "id": "10000", "key": "TEST-1", "fields": {"attachment": []}}"#,
+            )
+            .create();
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+        let attachments = Issues::new(&jira).list_attachments("TEST-1").unwrap();
+
+        mock_server.assert();
+        assert!(attachments.is_empty());
+    }
+}
+
+mod watch_tests {
+    use super::*;
+    use gouqi::Jira;
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    #[test]
+    fn watch_and_unwatch_on_cloud_resolve_myself_once() {
+        let mut server = Server::new();
+        let myself = server
+            .mock("GET", "/rest/api/latest/myself")
+            .with_body(
+                r#"{"self": "http://jira/rest/api/3/user?accountId=5b10ac", "accountId": "5b10ac", "displayName": "Ann", "active": true}"#,
+            )
+            .expect(1)
+            .create();
+        let watch = server
+            .mock("POST", "/rest/api/latest/issue/TEST-1/watchers")
+            .match_body(Matcher::Json(json!("5b10ac")))
+            .with_status(204)
+            .create();
+        let unwatch = server
+            .mock("DELETE", "/rest/api/latest/issue/TEST-1/watchers")
+            .match_query(Matcher::UrlEncoded("accountId".into(), "5b10ac".into()))
+            .with_status(204)
+            .create();
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+        jira.issues().watch("TEST-1").unwrap();
+        jira.issues().unwatch("TEST-1").unwrap();
+
+        myself.assert();
+        watch.assert();
+        unwatch.assert();
+    }
+
+    #[test]
+    fn watch_and_unwatch_on_server_use_the_user_name() {
+        let mut server = Server::new();
+        server
+            .mock("GET", "/rest/api/latest/myself")
+            .with_body(
+                r#"{"self": "http://jira/rest/api/2/user?username=ann", "name": "ann", "key": "ann", "displayName": "Ann", "active": true}"#,
+            )
+            .create();
+        let watch = server
+            .mock("POST", "/rest/api/latest/issue/TEST-1/watchers")
+            .match_body(Matcher::Json(json!("ann")))
+            .with_status(204)
+            .create();
+        let unwatch = server
+            .mock("DELETE", "/rest/api/latest/issue/TEST-1/watchers")
+            .match_query(Matcher::UrlEncoded("username".into(), "ann".into()))
+            .with_status(204)
+            .create();
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+        let issues = Issues::new(&jira);
+        issues.watch("TEST-1").unwrap();
+        issues.unwatch("TEST-1").unwrap();
+
+        watch.assert();
+        unwatch.assert();
+    }
+}
+
+mod vote_tests {
+    use super::*;
+    use gouqi::Jira;
+    use mockito::Server;
+
+    #[test]
+    fn vote_status() {
+        let mut server = Server::new();
+        let mock_server = server
+            .mock("GET", "/rest/api/latest/issue/TEST-1/votes")
+            .with_body(
+                r#"{
+                    "self": "http://jira/rest/api/2/issue/TEST-1/votes",
+                    "votes": 24,
+                    "hasVoted": true,
+                    "voters": []
+                }"#,
+            )
+            .create();
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+        let votes = Issues::new(&jira).vote_status("TEST-1").unwrap();
+
+        mock_server.assert();
+        assert_eq!(votes.votes, 24);
+        assert!(votes.has_voted);
+    }
+
+    #[test]
+    fn vote_status_not_found() {
+        let mut server = Server::new();
+        server
+            .mock("GET", "/rest/api/latest/issue/NOPE-1/votes")
+            .with_status(404)
+            .with_body(r#"{"errorMessages": ["Issue Does Not Exist"], "errors": {}}"#)
+            .create();
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+        assert!(Issues::new(&jira).vote_status("NOPE-1").is_err());
+    }
+}
+
+mod create_validated_tests {
+    use super::*;
+    use gouqi::{Error, Jira};
+    use mockito::{Matcher, Mock, Server, ServerGuard};
+
+    const CREATE_META: &str = r#"{
+        "projects": [{
+            "id": "10000",
+            "key": "TEST",
+            "name": "Test",
+            "issuetypes": [{
+                "id": "1",
+                "name": "Bug",
+                "subtask": false,
+                "fields": {
+                    "project": {
+                        "required": true,
+                        "name": "Project",
+                        "hasDefaultValue": false,
+                        "allowedValues": [{ "id": "10000", "key": "TEST", "name": "Test" }]
+                    },
+                    "issuetype": {
+                        "required": true,
+                        "name": "Issue Type",
+                        "hasDefaultValue": false,
+                        "allowedValues": [{ "id": "1", "name": "Bug" }]
+                    },
+                    "summary": { "required": true, "name": "Summary", "hasDefaultValue": false },
+                    "priority": {
+                        "required": false,
+                        "name": "Priority",
+                        "hasDefaultValue": true,
+                        "allowedValues": [{ "id": "2", "name": "High" }, { "id": "3", "name": "Medium" }]
+                    },
+                    "customfield_10010": { "required": true, "name": "Severity", "hasDefaultValue": false }
+                }
+            }]
+        }]
+    }"#;
+
+    fn create_meta(server: &mut ServerGuard) -> Mock {
+        server
+            .mock("GET", "/rest/api/latest/issue/createmeta")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("projectKeys".into(), "TEST".into()),
+                Matcher::UrlEncoded("issuetypeNames".into(), "Bug".into()),
+                Matcher::UrlEncoded("expand".into(), "projects.issuetypes.fields".into()),
+            ]))
+            .with_body(CREATE_META)
+            .create()
+    }
+
+    #[test]
+    fn missing_required_field_is_rejected_before_create() {
+        let mut server = Server::new();
+        let meta = create_meta(&mut server);
+        let create = server
+            .mock("POST", "/rest/api/latest/issue")
+            .expect(0)
+            .create();
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+        let issue = CreateCustomIssue::builder("TEST", "Bug", "Crash on start")
+            .priority("Urgent")
+            .field("customfield_99999", "x")
+            .build()
+            .unwrap();
+        let result = Issues::new(&jira).create_validated(issue);
+
+        meta.assert();
+        create.assert();
+        match result {
+            Err(Error::InvalidInput(message)) => {
+                assert!(message.contains("missing required field Severity (customfield_10010)"));
+                assert!(message
+                    .contains(r#"value {"name":"Urgent"} is not allowed for field Priority"#));
+                assert!(message.contains("field customfield_99999 is not on the create screen"));
+                assert!(!message.contains("Summary"));
+            }
+            other => panic!("expected invalid input, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn valid_issue_is_created_and_createmeta_cached() {
+        let mut server = Server::new();
+        let meta = create_meta(&mut server).expect(1);
+        let create = server
+            .mock("POST", "/rest/api/latest/issue")
+            .with_status(201)
+            .with_body(
+                r#"{"id": "10001", "key": "TEST-1", "self": "http://jira/rest/api/2/issue/10001"}"#,
+            )
+            .expect(2)
+            .create();
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+        for summary in ["First", "Second"] {
+            let issue = CreateCustomIssue::builder("TEST", "Bug", summary)
+                .priority("High")
+                .field("customfield_10010", "S2")
+                .build()
+                .unwrap();
+            let created = jira.issues().create_validated(issue).unwrap();
+            assert_eq!(created.key, "TEST-1");
+        }
+
+        meta.assert();
+        create.assert();
+    }
+}
+
+mod transition_tests {
+    use super::*;
+    use gouqi::{Error, Jira, TransitionTriggerOptions};
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    #[test]
+    fn bulk_transition_reports_failures_per_issue() {
+        let mut server = Server::new();
+        let done = server
+            .mock("POST", "/rest/api/latest/issue/TEST-1/transitions")
+            .match_body(Matcher::Json(
+                json!({ "transition": { "id": "31" }, "fields": {} }),
+            ))
+            .with_status(204)
+            .create();
+        let invalid = server
+            .mock("POST", "/rest/api/latest/issue/TEST-2/transitions")
+            .with_status(400)
+            .with_body(r#"{"errorMessages": ["Transition id '99' is not valid for this issue."], "errors": {}}"#)
+            .create();
+        let also_done = server
+            .mock("POST", "/rest/api/latest/issue/TEST-3/transitions")
+            .with_status(204)
+            .create();
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+        let report = Issues::new(&jira)
+            .bulk_transition(vec![
+                ("TEST-1".to_owned(), TransitionTriggerOptions::new("31")),
+                ("TEST-2".to_owned(), TransitionTriggerOptions::new("99")),
+                ("TEST-3".to_owned(), TransitionTriggerOptions::new("31")),
+            ])
+            .unwrap();
+
+        done.assert();
+        invalid.assert();
+        also_done.assert();
+        assert_eq!(report.transitioned, vec!["TEST-1", "TEST-3"]);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].key, "TEST-2");
+        match &report.errors[0].error {
+            Error::Fault { errors, .. } => assert_eq!(
+                errors.error_messages,
+                vec!["Transition id '99' is not valid for this issue."]
+            ),
+            other => panic!("expected a fault, got {other:?}"),
+        }
+    }
+
+    const TRANSITIONS: &str = r#"{
+        "transitions": [
+            { "id": "11", "name": "Start", "to": { "id": "3", "name": "In Progress" } },
+            { "id": "31", "name": "Finish", "to": { "id": "10001", "name": "Done" } }
+        ]
+    }"#;
+
+    #[test]
+    fn transition_to_status_name() {
+        let mut server = Server::new();
+        let list = server
+            .mock("GET", "/rest/api/latest/issue/TEST-1/transitions")
+            .match_query(Matcher::UrlEncoded(
+                "expand".into(),
+                "transitions.fields".into(),
+            ))
+            .with_body(TRANSITIONS)
+            .create();
+        let trigger = server
+            .mock("POST", "/rest/api/latest/issue/TEST-1/transitions")
+            .match_body(Matcher::Json(json!({
+                "transition": { "id": "31" },
+                "fields": { "resolution": { "name": "Fixed" } }
+            })))
+            .with_status(204)
+            .create();
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+        let mut fields = std::collections::BTreeMap::new();
+        fields.insert("resolution".to_owned(), json!({ "name": "Fixed" }));
+        Issues::new(&jira)
+            .transition_to("TEST-1", "done", Some(fields))
+            .unwrap();
+
+        list.assert();
+        trigger.assert();
+    }
+
+    #[test]
+    fn transition_to_unreachable_status() {
+        let mut server = Server::new();
+        server
+            .mock("GET", "/rest/api/latest/issue/TEST-1/transitions")
+            .match_query(Matcher::Any)
+            .with_body(TRANSITIONS)
+            .create();
+        let trigger = server
+            .mock("POST", "/rest/api/latest/issue/TEST-1/transitions")
+            .expect(0)
+            .create();
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+        let result = Issues::new(&jira).transition_to("TEST-1", "Closed", None);
+
+        trigger.assert();
+        match result {
+            Err(Error::InvalidInput(message)) => assert_eq!(
+                message,
+                "no transition of TEST-1 leads to Closed, reachable statuses: In Progress, Done"
+            ),
+            other => panic!("expected invalid input, got {other:?}"),
+        }
+    }
+}
+
+mod edit_tests {
+    use super::*;
+    use gouqi::Jira;
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    fn history(created: &str, field: &str, from: &str, to: &str) -> serde_json::Value {
+        json!({
+            "author": {
+                "self": "http://jira/rest/api/2/user?username=fred",
+                "name": "fred",
+                "displayName": "Fred",
+                "active": true
+            },
+            "created": created,
+            "items": [{ "field": field, "from": null, "fromString": from, "to": null, "toString": to }]
+        })
+    }
+
+    fn histories(count: usize) -> Vec<serde_json::Value> {
+        (0..count)
+            .map(|i| {
+                history(
+                    "2024-01-01T10:00:00.000+0000",
+                    "labels",
+                    &format!("old-{i}"),
+                    &format!("new-{i}"),
+                )
+            })
+            .collect()
+    }
+
+    fn edit_and_return_mocks(
+        server: &mut Server,
+        before: usize,
+        added: Vec<serde_json::Value>,
+    ) -> Vec<mockito::Mock> {
+        let url = "/rest/api/latest/issue/TEST-1/changelog";
+        vec![
+            server
+                .mock("GET", url)
+                .match_query(Matcher::Exact("maxResults=1".into()))
+                .with_body(
+                    json!({
+                        "startAt": 0,
+                        "maxResults": 1,
+                        "total": before,
+                        "isLast": before <= 1,
+                        "values": histories(before.min(1))
+                    })
+                    .to_string(),
+                )
+                .create(),
+            server
+                .mock("PUT", "/rest/api/latest/issue/TEST-1")
+                .match_body(Matcher::Json(
+                    json!({ "fields": { "summary": "New title" } }),
+                ))
+                .with_status(204)
+                .create(),
+            server
+                .mock("GET", url)
+                .match_query(Matcher::Exact(format!("startAt={before}")))
+                .with_body(
+                    json!({
+                        "startAt": before,
+                        "maxResults": 100,
+                        "total": before + added.len(),
+                        "isLast": true,
+                        "values": added
+                    })
+                    .to_string(),
+                )
+                .create(),
+            server
+                .mock("GET", "/rest/api/latest/issue/TEST-1")
+                .match_query(Matcher::Missing)
+                .with_body(
+                    json!({
+                        "self": "http://jira/rest/api/2/issue/1",
+                        "id": "1",
+                        "key": "TEST-1",
+                        "fields": { "summary": "New title" }
+                    })
+                    .to_string(),
+                )
+                .create(),
+        ]
+    }
+
+    fn edit_summary(jira: &Jira) -> gouqi::Result<UpdateResult> {
+        Issues::new(jira).edit_and_return(
+            "TEST-1",
+            EditIssue {
+                fields: std::collections::BTreeMap::from([("summary".to_owned(), "New title")]),
+            },
+        )
+    }
+
+    #[test]
+    fn edit_and_return_reports_the_changes_of_the_edit() {
+        let mut server = Server::new();
+        let mocks = edit_and_return_mocks(
+            &mut server,
+            150,
+            vec![history(
+                "2024-03-05T08:30:00.000+0000",
+                "summary",
+                "Old title",
+                "New title",
+            )],
+        );
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+        let result = edit_summary(&jira).unwrap();
+
+        for mock in mocks {
+            mock.assert();
+        }
+        assert_eq!(result.issue.summary().as_deref(), Some("New title"));
+        assert_eq!(result.changes.len(), 1);
+        assert_eq!(result.changes[0].field, "summary");
+        assert_eq!(result.changes[0].from_string.as_deref(), Some("Old title"));
+        assert_eq!(result.changes[0].to_string.as_deref(), Some("New title"));
+    }
+
+    #[test]
+    fn edit_and_return_without_changes() {
+        let mut server = Server::new();
+        let mocks = edit_and_return_mocks(&mut server, 3, vec![]);
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+        let result = edit_summary(&jira).unwrap();
+
+        for mock in mocks {
+            mock.assert();
+        }
+        assert!(result.changes.is_empty());
+    }
+}
+
+mod create_and_get_tests {
+    use super::*;
+    use gouqi::{Jira, SearchOptions};
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    #[test]
+    fn create_and_get_fetches_the_new_issue() {
+        let mut server = Server::new();
+        let create = server
+            .mock("POST", "/rest/api/latest/issue")
+            .match_body(Matcher::PartialJson(
+                json!({ "fields": { "summary": "Fresh" } }),
+            ))
+            .with_status(201)
+            .with_body(
+                r#"{"id": "10001", "key": "TEST-9", "self": "http://jira/rest/api/2/issue/10001"}"#,
+            )
+            .create();
+        let get = server
+            .mock("GET", "/rest/api/latest/issue/TEST-9")
+            .match_query(Matcher::UrlEncoded("fields".into(), "summary,status".into()))
+            .with_body(
+                r#"{"self": "http://jira/rest/api/2/issue/10001", "id": "10001", "key": "TEST-9", "fields": {"summary": "Fresh"}}"#,
+            )
+            .create();
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+        let issue = Issues::new(&jira)
+            .create_and_get(
+                CreateCustomIssue::builder("TEST", "Task", "Fresh")
+                    .build()
+                    .unwrap(),
+                &SearchOptions::builder()
+                    .fields(vec!["summary", "status"])
+                    .build(),
+            )
+            .unwrap();
+
+        create.assert();
+        get.assert();
+        assert_eq!(issue.key, "TEST-9");
+        assert_eq!(issue.summary().as_deref(), Some("Fresh"));
+    }
+}
+
+mod list_tests {
+    use super::*;
+    use gouqi::{Board, Jira, SearchOptions};
+    use mockito::{Matcher, Server};
+
+    #[test]
+    fn list_board_issues_with_jql() {
+        let mut server = Server::new();
+        let mock_server = server
+            .mock("GET", "/rest/agile/latest/board/1/issue")
+            .match_query(Matcher::UrlEncoded(
+                "jql".into(),
+                "resolution = Unresolved".into(),
+            ))
+            .with_body(r#"{"total": 0, "maxResults": 50, "startAt": 0, "issues": []}"#)
+            .create();
+
+        let jira = Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+        let board: Board = serde_json::from_str(
+            r#"{"id": 1, "self": "http://jira/rest/agile/1.0/board/1", "name": "TEST", "type": "scrum"}"#,
+        )
+        .unwrap();
+        let results = Issues::new(&jira)
+            .list(
+                &board,
+                &SearchOptions::builder()
+                    .jql("resolution = Unresolved")
+                    .build(),
+            )
+            .unwrap();
+
+        mock_server.assert();
+        assert_eq!(results.total, 0);
+    }
 }
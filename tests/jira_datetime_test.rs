@@ -0,0 +1,77 @@
+use gouqi::jira_datetime;
+use gouqi::Issue;
+use serde::{Deserialize, Serialize};
+use time::macros::datetime;
+use time::OffsetDateTime;
+
+#[test]
+fn parse_formats() {
+    assert_eq!(
+        jira_datetime::parse("2024-01-01T09:00:00.000+0000").unwrap(),
+        datetime!(2024-01-01 09:00 +00:00)
+    );
+    assert_eq!(
+        jira_datetime::parse("2024-01-01T09:00:00.000+0530").unwrap(),
+        datetime!(2024-01-01 09:00 +05:30)
+    );
+    assert_eq!(
+        jira_datetime::parse("2024-01-01T09:00:00Z").unwrap(),
+        datetime!(2024-01-01 09:00 +00:00)
+    );
+    assert_eq!(
+        jira_datetime::parse("2024-01-31").unwrap(),
+        datetime!(2024-01-31 00:00 +00:00)
+    );
+    assert!(jira_datetime::parse("yesterday").is_err());
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Stamped {
+    #[serde(with = "jira_datetime")]
+    at: OffsetDateTime,
+    #[serde(default, with = "jira_datetime::option")]
+    maybe: Option<OffsetDateTime>,
+}
+
+#[test]
+fn serde_round_trip() {
+    let stamped: Stamped =
+        serde_json::from_str(r#"{"at": "2024-01-01T09:00:00.000+0000", "maybe": null}"#).unwrap();
+    assert_eq!(stamped.at, datetime!(2024-01-01 09:00 +00:00));
+    assert_eq!(stamped.maybe, None);
+
+    let json = serde_json::to_string(&stamped).unwrap();
+    assert_eq!(
+        json,
+        r#"{"at":"2024-01-01T09:00:00.000+0000","maybe":null}"#
+    );
+    assert_eq!(serde_json::from_str::<Stamped>(&json).unwrap(), stamped);
+}
+
+#[test]
+fn issue_datetime_field() {
+    let issue: Issue = serde_json::from_str(
+        r#"{
+            "self": "https://jira.com/rest/api/2/issue/1",
+            "id": "1",
+            "key": "MYPROJ-1",
+            "fields": {
+                "customfield_10010": "2024-01-01T09:00:00.000+0000",
+                "customfield_10011": "2024-02-29",
+                "customfield_10012": "not a date"
+            }
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        issue.datetime_field("customfield_10010").unwrap().unwrap(),
+        datetime!(2024-01-01 09:00 +00:00)
+    );
+    assert_eq!(
+        issue.datetime_field("customfield_10011").unwrap().unwrap(),
+        datetime!(2024-02-29 00:00 +00:00)
+    );
+    assert!(issue.datetime_field("customfield_10012").unwrap().is_err());
+    assert!(issue.datetime_field("customfield_10013").is_none());
+}
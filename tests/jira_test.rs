@@ -114,7 +114,7 @@ fn jira_http_post() {
         .create();
 
     let jira = Jira::new(url, Credentials::Anonymous).unwrap();
-    let body = EmptyBody::default();
+    let body = EmptyBody;
     jira.post::<EmptyResponse, EmptyBody>("api", "/endpoint", body)
         .unwrap();
     mock.assert();
@@ -134,8 +134,591 @@ fn jira_http_put() {
         .create();
 
     let jira = Jira::new(url, Credentials::Anonymous).unwrap();
-    let body = EmptyBody::default();
+    let body = EmptyBody;
     jira.put::<EmptyResponse, EmptyBody>("api", "/endpoint", body)
         .unwrap();
     mock.assert();
 }
+
+#[test]
+fn jira_my_permissions() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/rest/api/latest/mypermissions")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("projectKey".into(), "TEST".into()),
+            mockito::Matcher::UrlEncoded(
+                "permissions".into(),
+                "BROWSE_PROJECTS,DELETE_ISSUES".into(),
+            ),
+        ]))
+        .with_body(
+            r#"{
+                "permissions": {
+                    "BROWSE_PROJECTS": {
+                        "id": "10",
+                        "key": "BROWSE_PROJECTS",
+                        "name": "Browse Projects",
+                        "type": "PROJECT",
+                        "description": "Ability to browse projects",
+                        "havePermission": true
+                    },
+                    "DELETE_ISSUES": {
+                        "id": "16",
+                        "key": "DELETE_ISSUES",
+                        "name": "Delete Issues",
+                        "type": "PROJECT",
+                        "description": "Ability to delete issues",
+                        "havePermission": false
+                    }
+                }
+            }"#,
+        )
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let permissions = jira
+        .my_permissions(
+            Some("TEST".to_owned()),
+            None,
+            &["BROWSE_PROJECTS", "DELETE_ISSUES"],
+        )
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(permissions.get("BROWSE_PROJECTS"), Some(&true));
+    assert_eq!(permissions.get("DELETE_ISSUES"), Some(&false));
+}
+
+#[test]
+fn jira_my_permissions_without_filter() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/rest/api/latest/mypermissions")
+        .with_body(
+            r#"{"permissions": {"ADMINISTER": {"key": "ADMINISTER", "havePermission": false}}}"#,
+        )
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let permissions = jira.my_permissions(None, None, &[]).unwrap();
+
+    mock.assert();
+    assert_eq!(permissions.len(), 1);
+}
+
+#[test]
+fn jira_http_get_with_context_path() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/jira/rest/api/latest/endpoint")
+        .with_status(201)
+        .create();
+
+    let jira = Jira::new(format!("{}/jira", server.url()), Credentials::Anonymous).unwrap();
+    jira.get::<EmptyResponse>("api", "/endpoint").unwrap();
+    mock.assert();
+}
+
+#[test]
+fn jira_http_get_with_base_path() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/jira/rest/api/latest/endpoint")
+        .with_status(201)
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous)
+        .unwrap()
+        .with_base_path("/jira");
+    jira.get::<EmptyResponse>("api", "/endpoint").unwrap();
+    mock.assert();
+}
+
+#[test]
+fn jira_http_get_with_base_api_path() {
+    let mut server = mockito::Server::new();
+    let api = server
+        .mock("GET", "/gateway/custom/rest/api/2/issue/TEST-1")
+        .with_body(r#"{"self": "x", "id": "1", "key": "TEST-1", "fields": {}}"#)
+        .create();
+    let agile = server
+        .mock("GET", "/gateway/rest/agile/latest/board/1")
+        .with_status(201)
+        .create();
+
+    let jira = Jira::builder(server.url())
+        .base_path("/gateway")
+        .base_api_path("api", "/custom/rest/api/2/")
+        .build()
+        .unwrap();
+    assert_eq!(jira.issues().get("TEST-1").unwrap().key, "TEST-1");
+    jira.get::<EmptyResponse>("agile", "/board/1").unwrap();
+
+    api.assert();
+    agile.assert();
+}
+
+const SERVER_INFO: &str = r#"{
+    "baseUrl": "http://jira",
+    "version": "9.12.0",
+    "buildNumber": 912000,
+    "deploymentType": "Server",
+    "serverTitle": "Jira"
+}"#;
+
+#[test]
+fn jira_health_check_authenticated() {
+    let mut server = mockito::Server::new();
+    let info = server
+        .mock("GET", "/rest/api/latest/serverInfo")
+        .with_body(SERVER_INFO)
+        .create();
+    let myself = server
+        .mock("GET", "/rest/api/latest/myself")
+        .match_header("authorization", "Bearer 12345")
+        .with_body(
+            r#"{"self": "http://jira/rest/api/2/user?username=fred", "name": "fred", "displayName": "Fred", "active": true}"#,
+        )
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Bearer("12345".to_owned())).unwrap();
+    let status = jira.health_check().unwrap();
+
+    info.assert();
+    myself.assert();
+    assert!(status.reachable);
+    assert!(status.authenticated);
+    assert_eq!(status.deployment_type.as_deref(), Some("Server"));
+}
+
+#[test]
+fn jira_health_check_rejected_credentials() {
+    let mut server = mockito::Server::new();
+    server
+        .mock("GET", "/rest/api/latest/serverInfo")
+        .with_body(SERVER_INFO)
+        .create();
+    server
+        .mock("GET", "/rest/api/latest/myself")
+        .with_status(401)
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Bearer("wrong".to_owned())).unwrap();
+    let status = jira.health_check().unwrap();
+
+    assert!(status.reachable);
+    assert!(!status.authenticated);
+}
+
+#[test]
+fn jira_health_check_anonymous() {
+    let mut server = mockito::Server::new();
+    let info = server
+        .mock("GET", "/rest/api/latest/serverInfo")
+        .with_body(SERVER_INFO)
+        .create();
+    let myself = server
+        .mock("GET", "/rest/api/latest/myself")
+        .expect(0)
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let status = jira.health_check().unwrap();
+
+    info.assert();
+    myself.assert();
+    assert!(status.reachable);
+    assert!(!status.authenticated);
+}
+
+#[test]
+fn jira_health_check_unreachable() {
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let jira = Jira::new(
+        format!("http://127.0.0.1:{port}"),
+        Credentials::Bearer("12345".to_owned()),
+    )
+    .unwrap();
+
+    let status = jira.health_check().unwrap();
+    assert!(!status.reachable);
+    assert!(!status.authenticated);
+    assert_eq!(status.deployment_type, None);
+}
+
+#[test]
+fn read_only_blocks_mutations() {
+    let mut server = mockito::Server::new();
+    let writes = server
+        .mock("POST", mockito::Matcher::Any)
+        .expect(0)
+        .create();
+    let updates = server.mock("PUT", mockito::Matcher::Any).expect(0).create();
+    let deletes = server
+        .mock("DELETE", mockito::Matcher::Any)
+        .expect(0)
+        .create();
+    let reads = server
+        .mock("GET", "/rest/api/latest/issue/TEST-1")
+        .with_body(
+            r#"{"self": "http://jira/rest/api/2/issue/10000", "id": "10000", "key": "TEST-1", "fields": {}}"#,
+        )
+        .create();
+
+    let mut jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    jira.set_read_only(true);
+    assert!(jira.is_read_only());
+
+    let assert_blocked = |result: Result<()>| match result {
+        Err(Error::InvalidInput(message)) => assert_eq!(message, "client is read-only"),
+        other => panic!("expected the request to be blocked, got {other:?}"),
+    };
+    let issues = jira.issues();
+    assert_blocked(
+        issues
+            .create_from_custom_issue(
+                CreateCustomIssue::builder("TEST", "Task", "New")
+                    .build()
+                    .unwrap(),
+            )
+            .map(|_| ()),
+    );
+    assert_blocked(issues.edit(
+        "TEST-1",
+        EditIssue {
+            fields: std::collections::BTreeMap::from([("summary".to_owned(), "Changed")]),
+        },
+    ));
+    assert_blocked(jira.projects().delete("TEST", false));
+    assert_blocked(
+        jira.attachments()
+            .upload("TEST-1", "notes.txt", b"notes".to_vec())
+            .map(|_| ()),
+    );
+    assert_eq!(issues.get("TEST-1").unwrap().key, "TEST-1");
+
+    writes.assert();
+    updates.assert();
+    deletes.assert();
+    reads.assert();
+}
+
+#[test]
+fn builder_applies_options() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/jira/rest/api/latest/endpoint")
+        .match_header("authorization", "Bearer 12345")
+        .match_header("user-agent", "report-bot/1.0")
+        .create();
+
+    let jira = Jira::builder(server.url())
+        .credentials(Credentials::Bearer("12345".to_string()))
+        .user_agent("report-bot/1.0")
+        .timeout(std::time::Duration::from_secs(5))
+        .base_path("/jira")
+        .read_only(true)
+        .build()
+        .unwrap();
+
+    assert!(jira.is_read_only());
+    jira.get::<EmptyResponse>("api", "/endpoint").unwrap();
+    mock.assert();
+}
+
+#[test]
+fn builder_applies_pool_settings() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/rest/api/latest/endpoint")
+        .expect(2)
+        .create();
+
+    let jira = Jira::builder(server.url())
+        .pool_max_idle_per_host(1)
+        .pool_idle_timeout(std::time::Duration::from_secs(5))
+        .build()
+        .unwrap();
+
+    jira.get::<EmptyResponse>("api", "/endpoint").unwrap();
+    jira.clone()
+        .get::<EmptyResponse>("api", "/endpoint")
+        .unwrap();
+    mock.assert();
+}
+
+#[test]
+fn builder_default_issue_fields_apply_unless_overridden() {
+    let mut server = mockito::Server::new();
+    let issue = r#"{"self": "x", "id": "1", "key": "TEST-1", "fields": {}}"#;
+    let defaulted_get = server
+        .mock("GET", "/rest/api/latest/issue/TEST-1")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "fields".into(),
+            "key,summary,status".into(),
+        ))
+        .with_body(issue)
+        .create();
+    let overridden_get = server
+        .mock("GET", "/rest/api/latest/issue/TEST-1")
+        .match_query(mockito::Matcher::UrlEncoded("fields".into(), "*all".into()))
+        .with_body(issue)
+        .create();
+    let defaulted_search = server
+        .mock("GET", "/rest/api/latest/search")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("jql".into(), "project = TEST".into()),
+            mockito::Matcher::UrlEncoded("fields".into(), "key,summary,status".into()),
+        ]))
+        .with_body(r#"{"total": 0, "maxResults": 50, "startAt": 0, "issues": []}"#)
+        .create();
+
+    let jira = Jira::builder(server.url())
+        .default_issue_fields(vec!["key", "summary", "status"])
+        .build()
+        .unwrap();
+    let defaulted = jira.issues().get("TEST-1").unwrap();
+    jira.issues()
+        .get_with_options(
+            "TEST-1",
+            &SearchOptions::builder().fields(vec!["*all"]).build(),
+        )
+        .unwrap();
+    jira.search()
+        .list("project = TEST", &SearchOptions::default())
+        .unwrap();
+
+    defaulted_get.assert();
+    overridden_get.assert();
+    defaulted_search.assert();
+    assert!(defaulted.was_requested("summary"));
+    assert!(!defaulted.was_requested("assignee"));
+}
+
+#[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+#[test]
+fn client_builds_with_the_selected_tls_backend() {
+    let jira = Jira::builder("https://jira.example.com")
+        .credentials(Credentials::PersonalAccessToken("token".to_owned()))
+        .build();
+    assert!(jira.is_ok());
+}
+
+#[test]
+fn clones_share_configuration_until_changed() {
+    // copying a configuration this large a hundred thousand times would
+    // take minutes, sharing it takes milliseconds
+    let fields = (0..10_000)
+        .map(|n| format!("customfield_{n}"))
+        .collect::<Vec<_>>();
+    let jira = Jira::builder(JIRA_HOST)
+        .default_issue_fields(fields)
+        .build()
+        .unwrap();
+    let started = std::time::Instant::now();
+    let clones = (0..100_000).map(|_| jira.clone()).collect::<Vec<_>>();
+    assert!(started.elapsed() < std::time::Duration::from_secs(5));
+
+    let mut changed = clones[0].clone();
+    changed.set_read_only(true);
+    assert!(changed.is_read_only());
+    assert!(!jira.is_read_only());
+    assert!(!clones[1].is_read_only());
+}
+
+#[derive(Default)]
+struct Signing {
+    statuses: std::sync::Mutex<Vec<u16>>,
+}
+
+impl RequestMiddleware for Signing {
+    fn before_send(&self, req: &mut reqwest::blocking::Request) {
+        req.headers_mut()
+            .insert("x-signature", "signed".parse().unwrap());
+    }
+
+    fn after_receive(&self, resp: &reqwest::blocking::Response) {
+        self.statuses.lock().unwrap().push(resp.status().as_u16());
+    }
+}
+
+#[test]
+fn middleware_runs_around_every_request() {
+    let mut server = mockito::Server::new();
+    let ok = server
+        .mock("GET", "/rest/api/latest/endpoint")
+        .match_header("x-signature", "signed")
+        .match_header("authorization", "Bearer 12345")
+        .with_status(200)
+        .create();
+    let missing = server
+        .mock("GET", "/rest/api/latest/missing")
+        .match_header("x-signature", "signed")
+        .with_status(404)
+        .create();
+
+    let signing = std::sync::Arc::new(Signing::default());
+    let jira = Jira::builder(server.url())
+        .credentials(Credentials::Bearer("12345".to_owned()))
+        .middleware(signing.clone())
+        .build()
+        .unwrap();
+    jira.get::<EmptyResponse>("api", "/endpoint").unwrap();
+    assert!(matches!(
+        jira.get::<EmptyResponse>("api", "/missing"),
+        Err(Error::NotFound)
+    ));
+
+    ok.assert();
+    missing.assert();
+    assert_eq!(*signing.statuses.lock().unwrap(), vec![200, 404]);
+}
+
+#[test]
+fn builder_defaults_match_new() {
+    let jira = Jira::builder(JIRA_HOST).build().unwrap();
+    assert!(!jira.is_read_only());
+    assert!(matches!(
+        Jira::builder("not a url").build(),
+        Err(Error::ParseError(_))
+    ));
+}
+
+#[test]
+fn raw_request_patch_with_headers() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("PATCH", "/rest/api/latest/endpoint")
+        .match_header("authorization", "Bearer 12345")
+        .match_header("x-experimental", "opt-in")
+        .match_body(mockito::Matcher::Json(
+            serde_json::json!({ "name": "Renamed" }),
+        ))
+        .with_body(r#"{"id": "1", "name": "Renamed"}"#)
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Bearer("12345".to_string())).unwrap();
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert("x-experimental", "opt-in".parse().unwrap());
+    let response = jira
+        .raw_request(
+            reqwest::Method::PATCH,
+            "api",
+            "/endpoint",
+            headers,
+            Some(serde_json::json!({ "name": "Renamed" })),
+        )
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(response["name"], "Renamed");
+}
+
+#[test]
+fn raw_request_maps_errors_and_empty_bodies() {
+    let mut server = mockito::Server::new();
+    server
+        .mock("DELETE", "/rest/api/latest/gone")
+        .with_status(204)
+        .create();
+    server
+        .mock("PATCH", "/rest/api/latest/invalid")
+        .with_status(400)
+        .with_body(r#"{"errorMessages": ["Bad field"], "errors": {}}"#)
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let headers = reqwest::header::HeaderMap::new;
+    assert_eq!(
+        jira.raw_request(reqwest::Method::DELETE, "api", "/gone", headers(), None)
+            .unwrap(),
+        serde_json::Value::Null
+    );
+    match jira.raw_request(reqwest::Method::PATCH, "api", "/invalid", headers(), None) {
+        Err(Error::Fault { code, errors }) => {
+            assert_eq!(code.as_u16(), 400);
+            assert_eq!(errors.error_messages, vec!["Bad field"]);
+        }
+        other => panic!("expected a fault, got {other:?}"),
+    }
+}
+
+#[test]
+fn credentials_headers() {
+    let cases = [
+        (
+            Credentials::Basic("user@example.com".to_string(), "api-token".to_string()),
+            "Basic dXNlckBleGFtcGxlLmNvbTphcGktdG9rZW4=",
+        ),
+        (Credentials::Bearer("12345".to_string()), "Bearer 12345"),
+        (
+            Credentials::PersonalAccessToken("pat-12345".to_string()),
+            "Bearer pat-12345",
+        ),
+    ];
+
+    for (credentials, expected) in cases {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", "/rest/api/latest/endpoint")
+            .match_header("authorization", expected)
+            .create();
+
+        let jira = Jira::new(server.url(), credentials).unwrap();
+        jira.get::<EmptyResponse>("api", "/endpoint").unwrap();
+        mock.assert();
+    }
+}
+
+#[test]
+fn anonymous_sends_no_authorization() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/rest/api/latest/endpoint")
+        .match_header("authorization", mockito::Matcher::Missing)
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    jira.get::<EmptyResponse>("api", "/endpoint").unwrap();
+    mock.assert();
+}
+
+#[test]
+fn injected_http_client_is_used() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/rest/api/latest/endpoint")
+        .match_header("x-team", "platform")
+        .match_header("authorization", "Bearer 12345")
+        .expect(2)
+        .create();
+
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert("x-team", "platform".parse().unwrap());
+    let client = reqwest::blocking::Client::builder()
+        .default_headers(headers)
+        .build()
+        .unwrap();
+
+    let jira = Jira::from_client(
+        server.url(),
+        Credentials::Bearer("12345".to_string()),
+        client.clone(),
+    )
+    .unwrap();
+    jira.get::<EmptyResponse>("api", "/endpoint").unwrap();
+
+    let jira = Jira::builder(server.url())
+        .credentials(Credentials::Bearer("12345".to_string()))
+        .http_client(client)
+        .build()
+        .unwrap();
+    jira.get::<EmptyResponse>("api", "/endpoint").unwrap();
+
+    mock.assert();
+}
@@ -0,0 +1,81 @@
+use gouqi::jql::*;
+use time::macros::{datetime, offset};
+use time::Duration;
+
+#[test]
+fn relative_dates() {
+    assert_eq!(relative(Duration::days(7)), "-1w");
+    assert_eq!(relative(Duration::days(3)), "-3d");
+    assert_eq!(relative(Duration::hours(4)), "-4h");
+    assert_eq!(relative(Duration::minutes(90)), "-90m");
+    assert_eq!(relative(Duration::days(-2)), "+2d");
+    assert_eq!(relative(Duration::ZERO), "-0m");
+}
+
+#[test]
+fn relative_clauses() {
+    assert_eq!(updated_within(Duration::days(7)), "updated >= -1w");
+    assert_eq!(created_within(Duration::hours(12)), "created >= -12h");
+}
+
+#[test]
+fn absolute_clauses() {
+    let when = datetime!(2024-01-31 09:05 +00:00);
+    assert_eq!(absolute(when), "\"2024/01/31 09:05\"");
+    assert_eq!(created_after(when), "created > \"2024/01/31 09:05\"");
+    assert_eq!(created_before(when), "created < \"2024/01/31 09:05\"");
+    assert_eq!(updated_after(when), "updated > \"2024/01/31 09:05\"");
+    assert_eq!(updated_before(when), "updated < \"2024/01/31 09:05\"");
+}
+
+#[test]
+fn absolute_dates_with_offsets() {
+    // the wall clock time is kept, it is read in the user's timezone
+    let when = datetime!(2024-01-31 09:05 +02:00);
+    assert_eq!(absolute(when), "\"2024/01/31 09:05\"");
+    // converted for a user in UTC and one in New York
+    assert_eq!(absolute_in(when, offset!(UTC)), "\"2024/01/31 07:05\"");
+    assert_eq!(absolute_in(when, offset!(-5)), "\"2024/01/31 02:05\"");
+    assert_eq!(
+        absolute_in(datetime!(2024-01-31 01:00 +02:00), offset!(UTC)),
+        "\"2024/01/30 23:00\""
+    );
+}
+
+#[test]
+fn start_of_day_function() {
+    assert_eq!(start_of_day(0), "startOfDay()");
+    assert_eq!(start_of_day(-1), "startOfDay(-1d)");
+    assert_eq!(start_of_day(2), "startOfDay(+2d)");
+}
+
+#[test]
+fn validate_accepts_well_formed_queries() {
+    for query in [
+        "",
+        "project = TEST",
+        r#"summary ~ "it's (not) broken" AND (status = Open OR status = "In Progress")"#,
+        r#"summary ~ "say \"hi\"""#,
+        "labels in ('a', 'b')\n\tORDER BY created DESC",
+    ] {
+        assert!(validate(query).is_ok(), "{query}");
+    }
+}
+
+#[test]
+fn validate_rejects_malformed_queries() {
+    for (query, expected) in [
+        (r#"summary ~ "unterminated"#, "unterminated string"),
+        ("summary ~ 'it's'", "unterminated string"),
+        ("(status = Open", "unclosed '(' at position 0"),
+        ("status = Open)", "unmatched ')' at position 13"),
+        ("project = TEST\u{0}", "control character"),
+    ] {
+        match validate(query) {
+            Err(gouqi::Error::InvalidInput(message)) => {
+                assert!(message.contains(expected), "{query}: {message}")
+            }
+            other => panic!("{query}: expected InvalidInput, got {other:?}"),
+        }
+    }
+}
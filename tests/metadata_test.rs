@@ -0,0 +1,89 @@
+extern crate gouqi;
+extern crate mockito;
+
+use gouqi::*;
+use mockito::Server;
+
+fn metadata_mocks(server: &mut Server) -> Vec<mockito::Mock> {
+    vec![
+        server
+            .mock("GET", "/rest/api/latest/status")
+            .with_body(
+                r#"[{
+                    "self": "http://jira/rest/api/2/status/3",
+                    "description": "Work has started",
+                    "iconUrl": "http://jira/images/icons/statuses/inprogress.png",
+                    "name": "In Progress",
+                    "id": "3"
+                }]"#,
+            )
+            .expect(1)
+            .create(),
+        server
+            .mock("GET", "/rest/api/latest/priority")
+            .with_body(
+                r#"[{
+                    "self": "http://jira/rest/api/2/priority/1",
+                    "iconUrl": "http://jira/images/icons/priorities/blocker.svg",
+                    "name": "Blocker",
+                    "id": "1"
+                }]"#,
+            )
+            .expect(1)
+            .create(),
+        server
+            .mock("GET", "/rest/api/latest/resolution")
+            .with_body(
+                r#"[{
+                    "self": "http://jira/rest/api/2/resolution/1",
+                    "id": "1",
+                    "name": "Fixed"
+                }]"#,
+            )
+            .expect(1)
+            .create(),
+    ]
+}
+
+#[test]
+fn second_lookup_does_not_hit_the_network() {
+    let mut server = Server::new();
+    let mocks = metadata_mocks(&mut server);
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let cache = jira.metadata_cache();
+    let status = cache.lookup_status("3").unwrap().unwrap();
+    assert_eq!(status.name, "In Progress");
+    let status = cache.lookup_status("3").unwrap().unwrap();
+    assert_eq!(status.name, "In Progress");
+    assert_eq!(cache.lookup_priority("1").unwrap().unwrap().name, "Blocker");
+    assert_eq!(cache.lookup_resolution("1").unwrap().unwrap().name, "Fixed");
+    assert!(cache.lookup_status("42").unwrap().is_none());
+
+    for mock in mocks {
+        mock.assert();
+    }
+}
+
+#[test]
+fn warm_up_is_shared_by_copies_of_the_client() {
+    let mut server = Server::new();
+    let mocks = metadata_mocks(&mut server);
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    jira.metadata_cache().warm_up().unwrap();
+    let copy = jira.clone();
+    copy.metadata_cache().warm_up().unwrap();
+    assert_eq!(
+        copy.metadata_cache()
+            .lookup_priority("1")
+            .unwrap()
+            .unwrap()
+            .name,
+        "Blocker"
+    );
+
+    for mock in mocks {
+        mock.assert();
+    }
+}
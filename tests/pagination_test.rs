@@ -0,0 +1,151 @@
+extern crate gouqi;
+
+use gouqi::{PageFetcher, PageIter, Paginated, Result};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Page over a range of numbers, reporting either a total or an `isLast` flag
+#[derive(Debug)]
+struct Page {
+    start_at: u64,
+    max_results: u64,
+    total: Option<u64>,
+    is_last: Option<bool>,
+    values: Vec<u64>,
+}
+
+impl Paginated for Page {
+    type Item = u64;
+
+    fn start_at(&self) -> u64 {
+        self.start_at
+    }
+
+    fn max_results(&self) -> u64 {
+        self.max_results
+    }
+
+    fn total(&self) -> Option<u64> {
+        self.total
+    }
+
+    fn is_last(&self) -> Option<bool> {
+        self.is_last
+    }
+
+    fn items(&self) -> &[u64] {
+        &self.values
+    }
+
+    fn into_items(self) -> Vec<u64> {
+        self.values
+    }
+}
+
+#[derive(Debug)]
+struct Numbers {
+    count: u64,
+    with_total: bool,
+    with_is_last: bool,
+    requests: Rc<RefCell<Vec<u64>>>,
+}
+
+impl Numbers {
+    fn new(count: u64) -> Numbers {
+        Numbers {
+            count,
+            with_total: true,
+            with_is_last: false,
+            requests: Rc::default(),
+        }
+    }
+
+    /// Collects all values, returning them with the offsets of the pages requested
+    fn collect(self, page_size: u64) -> (Vec<u64>, Vec<u64>) {
+        let requests = self.requests.clone();
+        let first = self.fetch(0, page_size).unwrap();
+        let values = PageIter::new(first, self).collect();
+        let requests = requests.borrow().clone();
+        (values, requests)
+    }
+}
+
+impl PageFetcher for Numbers {
+    type Page = Page;
+
+    fn fetch(&self, start_at: u64, max_results: u64) -> Result<Page> {
+        self.requests.borrow_mut().push(start_at);
+        let end = (start_at + max_results).min(self.count);
+        Ok(Page {
+            start_at,
+            max_results,
+            total: Some(self.count).filter(|_| self.with_total),
+            is_last: Some(end >= self.count).filter(|_| self.with_is_last),
+            values: (start_at..end).collect(),
+        })
+    }
+}
+
+#[test]
+fn total_divisible_by_page_size() {
+    let (values, requests) = Numbers::new(6).collect(3);
+    assert_eq!(values, (0..6).collect::<Vec<_>>());
+    assert_eq!(requests, vec![0, 3]);
+}
+
+#[test]
+fn total_not_divisible_by_page_size() {
+    let (values, requests) = Numbers::new(7).collect(3);
+    assert_eq!(values, (0..7).collect::<Vec<_>>());
+    assert_eq!(requests, vec![0, 3, 6]);
+}
+
+#[test]
+fn single_partial_page() {
+    let (values, requests) = Numbers::new(2).collect(3);
+    assert_eq!(values, vec![0, 1]);
+    assert_eq!(requests, vec![0]);
+}
+
+#[test]
+fn empty_results() {
+    let (values, requests) = Numbers::new(0).collect(3);
+    assert!(values.is_empty());
+    assert_eq!(requests, vec![0]);
+}
+
+#[test]
+fn is_last_flag() {
+    let numbers = Numbers {
+        with_total: false,
+        with_is_last: true,
+        ..Numbers::new(6)
+    };
+    let (values, requests) = numbers.collect(3);
+    assert_eq!(values, (0..6).collect::<Vec<_>>());
+    assert_eq!(requests, vec![0, 3]);
+}
+
+#[test]
+fn without_total_or_is_last_stops_on_short_page() {
+    let numbers = Numbers {
+        with_total: false,
+        ..Numbers::new(7)
+    };
+    let (values, requests) = numbers.collect(3);
+    assert_eq!(values, (0..7).collect::<Vec<_>>());
+    assert_eq!(requests, vec![0, 3, 6]);
+}
+
+#[test]
+fn follows_server_page_size() {
+    // the server clamps the page size, so offsets follow the returned count
+    let numbers = Numbers::new(5);
+    let mut first = numbers.fetch(0, 3).unwrap();
+    first.values.truncate(2);
+    first.max_results = 2;
+    let requests = numbers.requests.clone();
+    let iter = PageIter::new(first, numbers);
+    assert_eq!(iter.collect::<Vec<_>>(), (0..5).collect::<Vec<_>>());
+    assert_eq!(*requests.borrow(), vec![0, 2, 4]);
+}
@@ -0,0 +1,263 @@
+extern crate gouqi;
+extern crate mockito;
+
+use gouqi::*;
+use mockito::Server;
+
+#[test]
+fn get_project() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/rest/api/latest/project/TEST")
+        .with_body(r#"{"id": "10000", "key": "TEST", "name": "Test project"}"#)
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let project = jira.projects().get("TEST").unwrap();
+
+    mock.assert();
+    assert_eq!(project.id, "10000");
+    assert_eq!(project.name, "Test project");
+}
+
+#[test]
+fn archive_project() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("PUT", "/rest/api/latest/project/TEST/archive")
+        .with_status(204)
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    jira.projects().archive("TEST").unwrap();
+    mock.assert();
+}
+
+#[test]
+fn restore_project() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("POST", "/rest/api/latest/project/TEST/restore")
+        .with_status(200)
+        .with_body(r#"{"id": "10000", "key": "TEST", "name": "Test project"}"#)
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let project = jira.projects().restore("TEST").unwrap();
+    mock.assert();
+    assert_eq!(project.key, "TEST");
+}
+
+#[test]
+fn delete_project_with_undo() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("DELETE", "/rest/api/latest/project/TEST?enableUndo=true")
+        .with_status(204)
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    jira.projects().delete("TEST", true).unwrap();
+    mock.assert();
+}
+
+#[test]
+fn archive_project_unsupported() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("PUT", "/rest/api/latest/project/TEST/archive")
+        .with_status(405)
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let result = jira.projects().archive("TEST");
+
+    mock.assert();
+    match result {
+        Err(Error::InvalidInput(message)) => assert_eq!(
+            message,
+            "project archiving is not supported by this Jira deployment"
+        ),
+        other => panic!("expected invalid input, got {other:?}"),
+    }
+}
+
+#[test]
+fn archive_unknown_project() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("PUT", "/rest/api/latest/project/NOPE/archive")
+        .with_status(404)
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let result = jira.projects().archive("NOPE");
+
+    mock.assert();
+    assert!(matches!(result, Err(Error::NotFound)));
+}
+
+#[test]
+fn restore_project_unsupported() {
+    let mut server = Server::new();
+    let unsupported = server
+        .mock("POST", "/rest/api/latest/project/TEST/restore")
+        .with_status(405)
+        .create();
+    let missing = server
+        .mock("POST", "/rest/api/latest/project/NOPE/restore")
+        .with_status(404)
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    match jira.projects().restore("TEST") {
+        Err(Error::InvalidInput(message)) => assert_eq!(
+            message,
+            "project restoring is not supported by this Jira deployment"
+        ),
+        other => panic!("expected invalid input, got {other:?}"),
+    }
+    assert!(matches!(
+        jira.projects().restore("NOPE"),
+        Err(Error::NotFound)
+    ));
+
+    unsupported.assert();
+    missing.assert();
+}
+
+#[test]
+fn search_projects_over_two_pages() {
+    let mut server = Server::new();
+    let first = server
+        .mock("GET", "/rest/api/latest/project/search")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("query".into(), "TE".into()),
+            mockito::Matcher::UrlEncoded("startAt".into(), "0".into()),
+        ]))
+        .with_body(
+            r#"{"startAt": 0, "maxResults": 2, "total": 3, "isLast": false, "values": [
+                {"id": "1", "key": "TE1", "name": "One"},
+                {"id": "2", "key": "TE2", "name": "Two"}
+            ]}"#,
+        )
+        .create();
+    let second = server
+        .mock("GET", "/rest/api/latest/project/search")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("query".into(), "TE".into()),
+            mockito::Matcher::UrlEncoded("startAt".into(), "2".into()),
+            mockito::Matcher::UrlEncoded("maxResults".into(), "2".into()),
+        ]))
+        .with_body(
+            r#"{"startAt": 2, "maxResults": 2, "total": 3, "isLast": true, "values": [
+                {"id": "3", "key": "TE3", "name": "Three"}
+            ]}"#,
+        )
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let options = SearchOptions::builder().query("TE").start_at(0).build();
+    let keys = jira
+        .projects()
+        .search_iter(&options)
+        .unwrap()
+        .map(|project| project.key)
+        .collect::<Vec<_>>();
+
+    assert_eq!(keys, vec!["TE1", "TE2", "TE3"]);
+    first.assert();
+    second.assert();
+}
+
+#[test]
+fn project_issue_types() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/rest/api/latest/project/TEST")
+        .with_body(
+            r#"{"id": "10000", "key": "TEST", "name": "Test project", "issueTypes": [
+                {"self": "http://jira/rest/api/2/issuetype/1", "id": "1", "description": "A problem", "iconUrl": "http://jira/bug.png", "name": "Bug", "subtask": false},
+                {"self": "http://jira/rest/api/2/issuetype/5", "id": "5", "iconUrl": "http://jira/subtask.png", "name": "Sub-task", "subtask": true}
+            ]}"#,
+        )
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let issue_types = jira.projects().issue_types("TEST").unwrap();
+
+    mock.assert();
+    assert_eq!(
+        issue_types
+            .iter()
+            .map(|issue_type| (issue_type.name.as_str(), issue_type.subtask))
+            .collect::<Vec<_>>(),
+        vec![("Bug", false), ("Sub-task", true)]
+    );
+}
+
+#[test]
+fn project_notification_scheme() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/rest/api/latest/project/TEST/notificationscheme")
+        .with_body(
+            r#"{
+                "expand": "notificationSchemeEvents,user,group,projectRole,field,all",
+                "id": 10100,
+                "self": "http://jira/rest/api/2/notificationscheme/10100",
+                "name": "Default Notification Scheme",
+                "description": "",
+                "notificationSchemeEvents": [{
+                    "event": {"id": 1, "name": "Issue created", "description": "This is the 'issue created' event."},
+                    "notifications": [
+                        {"id": 1, "notificationType": "CurrentAssignee"},
+                        {"id": 2, "notificationType": "Group", "parameter": "jira-administrators", "group": {"name": "jira-administrators"}}
+                    ]
+                }]
+            }"#,
+        )
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let scheme = jira.projects().notification_scheme("TEST").unwrap();
+
+    mock.assert();
+    assert_eq!(scheme.id, 10100);
+    assert_eq!(scheme.name, "Default Notification Scheme");
+    assert_eq!(scheme.events[0].event.name, "Issue created");
+    let notifications = &scheme.events[0].notifications;
+    assert_eq!(notifications[0].notification_type, "CurrentAssignee");
+    assert_eq!(notifications[0].parameter, None);
+    assert_eq!(
+        notifications[1].parameter.as_deref(),
+        Some("jira-administrators")
+    );
+}
+
+#[test]
+fn project_security_levels() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/rest/api/latest/project/TEST/securitylevel")
+        .with_body(
+            r#"{"levels": [
+                {"self": "http://jira/rest/api/2/securitylevel/10021", "id": "10021", "description": "Only the reporter and internal staff can see this issue.", "name": "Reporter Only"},
+                {"self": "http://jira/rest/api/2/securitylevel/10022", "id": "10022", "name": "Staff"}
+            ]}"#,
+        )
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let levels = jira.projects().security_levels("TEST").unwrap();
+
+    mock.assert();
+    assert_eq!(
+        levels
+            .iter()
+            .map(|level| (level.id.as_str(), level.name.as_str()))
+            .collect::<Vec<_>>(),
+        vec![("10021", "Reporter Only"), ("10022", "Staff")]
+    );
+    assert_eq!(levels[1].description, None);
+}
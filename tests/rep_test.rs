@@ -74,3 +74,639 @@ fn issue_getters() {
         expected_comment_updated_date
     );
 }
+
+#[test]
+fn issue_custom_fields() {
+    let issue_str = r#"{
+        "self": "https://jira.com/rest/agile/1.0/issue/1234",
+        "id": "1234",
+        "key": "MYPROJ-1234",
+        "fields": {
+            "summary": "A summary",
+            "customfield_10001": "Team A",
+            "customfield_10002": 5
+        }
+    }"#;
+
+    let issue: Issue = serde_json::from_str(issue_str).unwrap();
+
+    let custom: Vec<&String> = issue.custom_fields().map(|(name, _)| name).collect();
+    assert_eq!(custom, vec!["customfield_10001", "customfield_10002"]);
+    assert_eq!(issue.all_fields().count(), 3);
+    assert_eq!(
+        issue
+            .custom_fields()
+            .find(|(name, _)| *name == "customfield_10002")
+            .map(|(_, value)| value.clone()),
+        Some(serde_json::json!(5))
+    );
+}
+
+#[test]
+fn issue_subtasks() {
+    let issue_str = r#"{
+        "self": "https://jira.com/rest/api/2/issue/1234",
+        "id": "1234",
+        "key": "MYPROJ-1234",
+        "fields": {
+            "issuetype": {
+                "self": "https://jira.com/rest/api/2/issuetype/1",
+                "id": "1",
+                "description": "A task",
+                "iconUrl": "https://jira.com/task.png",
+                "name": "Task",
+                "subtask": false
+            },
+            "subtasks": [
+                {
+                    "self": "https://jira.com/rest/api/2/issue/1235",
+                    "id": "1235",
+                    "key": "MYPROJ-1235",
+                    "fields": {
+                        "summary": "First subtask",
+                        "issuetype": {
+                            "self": "https://jira.com/rest/api/2/issuetype/5",
+                            "id": "5",
+                            "description": "A subtask",
+                            "iconUrl": "https://jira.com/subtask.png",
+                            "name": "Sub-task",
+                            "subtask": true
+                        }
+                    }
+                },
+                {
+                    "self": "https://jira.com/rest/api/2/issue/1236",
+                    "id": "1236",
+                    "key": "MYPROJ-1236",
+                    "fields": {
+                        "summary": "Second subtask"
+                    }
+                }
+            ]
+        }
+    }"#;
+
+    let issue: Issue = serde_json::from_str(issue_str).unwrap();
+
+    assert!(!issue.is_subtask());
+    let subtasks = issue.subtasks();
+    assert_eq!(subtasks.len(), 2);
+    assert_eq!(subtasks[0].key, "MYPROJ-1235");
+    assert!(subtasks[0].is_subtask());
+    assert_eq!(subtasks[1].summary(), Some("Second subtask".to_owned()));
+    assert!(!subtasks[1].is_subtask());
+    assert!(subtasks[1].subtasks().is_empty());
+}
+
+#[test]
+fn issue_time_tracking_seconds() {
+    let issue_str = r#"{
+        "self": "https://jira.com/rest/api/2/issue/1234",
+        "id": "1234",
+        "key": "MYPROJ-1234",
+        "fields": {
+            "timetracking": {
+                "originalEstimate": "1d",
+                "originalEstimateSeconds": 28800,
+                "remainingEstimate": "4h",
+                "remainingEstimateSeconds": 14400
+            }
+        }
+    }"#;
+
+    let issue: Issue = serde_json::from_str(issue_str).unwrap();
+
+    assert_eq!(issue.original_estimate_seconds(), Some(28800));
+    assert_eq!(issue.remaining_estimate_seconds(), Some(14400));
+    assert_eq!(issue.time_spent_seconds(), None);
+
+    let untracked: Issue = serde_json::from_str(
+        r#"{"self": "https://jira.com/rest/api/2/issue/1", "id": "1", "key": "MYPROJ-1", "fields": {}}"#,
+    )
+    .unwrap();
+    assert_eq!(untracked.original_estimate_seconds(), None);
+}
+
+#[test]
+fn comments_visible_to_role() {
+    let comments_str = r#"{
+        "self": "https://jira.com/rest/api/2/issue/10000/comment",
+        "maxResults": 3,
+        "total": 3,
+        "startAt": 0,
+        "comments": [
+            {
+                "self": "https://jira.com/rest/api/2/issue/10000/comment/1",
+                "id": "1",
+                "body": "Public"
+            },
+            {
+                "self": "https://jira.com/rest/api/2/issue/10000/comment/2",
+                "id": "2",
+                "body": "Admins only",
+                "visibility": { "type": "role", "value": "Administrators" }
+            },
+            {
+                "self": "https://jira.com/rest/api/2/issue/10000/comment/3",
+                "id": "3",
+                "body": "Developers only",
+                "visibility": { "type": "group", "value": "developers" }
+            }
+        ]
+    }"#;
+
+    let comments: Comments = serde_json::from_str(comments_str).unwrap();
+
+    assert!(!comments.comments[0].is_restricted());
+    assert!(comments.comments[1].is_restricted());
+    let visible: Vec<&str> = comments
+        .visible_to_role("Administrators")
+        .iter()
+        .map(|comment| comment.body.as_str())
+        .collect();
+    assert_eq!(visible, vec!["Public", "Admins only"]);
+    assert_eq!(comments.visible_to_role("Users").len(), 1);
+}
+
+#[test]
+fn issue_watches_and_votes() {
+    let issue_str = r#"{
+        "self": "https://jira.com/rest/api/2/issue/1234",
+        "id": "1234",
+        "key": "MYPROJ-1234",
+        "fields": {
+            "watches": {
+                "self": "https://jira.com/rest/api/2/issue/MYPROJ-1234/watchers",
+                "watchCount": 3,
+                "isWatching": true
+            },
+            "votes": {
+                "self": "https://jira.com/rest/api/2/issue/MYPROJ-1234/votes",
+                "votes": 7,
+                "hasVoted": false
+            }
+        }
+    }"#;
+
+    let issue: Issue = serde_json::from_str(issue_str).unwrap();
+
+    assert_eq!(issue.watch_count(), Some(3));
+    assert_eq!(issue.is_watching(), Some(true));
+    assert_eq!(issue.vote_count(), Some(7));
+    assert_eq!(issue.has_voted(), Some(false));
+
+    let bare: Issue = serde_json::from_str(
+        r#"{"self": "https://jira.com/rest/api/2/issue/1", "id": "1", "key": "MYPROJ-1", "fields": {}}"#,
+    )
+    .unwrap();
+    assert_eq!(bare.watch_count(), None);
+    assert_eq!(bare.has_voted(), None);
+}
+
+#[test]
+fn issue_permalink_with_context_path() {
+    let issue: Issue = serde_json::from_str(
+        r#"{"self": "https://host/jira/rest/api/2/issue/1", "id": "1", "key": "KEY-1", "fields": {}}"#,
+    )
+    .unwrap();
+
+    let jira = Jira::new("https://host/jira", Credentials::Anonymous).unwrap();
+    assert_eq!(issue.permalink(&jira), "https://host/jira/browse/KEY-1");
+
+    let jira = Jira::new("https://host/jira/", Credentials::Anonymous).unwrap();
+    assert_eq!(issue.permalink(&jira), "https://host/jira/browse/KEY-1");
+
+    let jira = Jira::new("https://host", Credentials::Anonymous)
+        .unwrap()
+        .with_base_path("jira");
+    assert_eq!(issue.permalink(&jira), "https://host/jira/browse/KEY-1");
+}
+
+#[test]
+fn issue_permalink_does_not_panic() {
+    let issue: Issue = serde_json::from_str(
+        r#"{"self": "https://host/rest/api/2/issue/1", "id": "1", "key": "KEY-1", "fields": {}}"#,
+    )
+    .unwrap();
+
+    // A url which can't be a base for joins used to panic
+    let jira = Jira::new("mailto:jira@example.com", Credentials::Anonymous).unwrap();
+    assert!(issue.try_permalink(&jira).is_err());
+    assert_eq!(
+        issue.permalink(&jira),
+        "mailto:jira@example.com/browse/KEY-1"
+    );
+}
+
+#[test]
+fn issue_permalink_encodes_key() {
+    let issue: Issue = serde_json::from_str(
+        r#"{"self": "https://host/rest/api/2/issue/1", "id": "1", "key": "KEY?1#2", "fields": {}}"#,
+    )
+    .unwrap();
+
+    let jira = Jira::new("https://host", Credentials::Anonymous).unwrap();
+    assert_eq!(
+        issue.try_permalink(&jira).unwrap(),
+        "https://host/browse/KEY%3F1%232"
+    );
+}
+
+#[test]
+fn issue_rendered_fields() {
+    let issue: Issue = serde_json::from_str(
+        r#"{
+            "self": "https://host/rest/api/2/issue/1",
+            "id": "1",
+            "key": "KEY-1",
+            "fields": { "description": "*bold* text", "environment": null },
+            "renderedFields": {
+                "description": "<p><b>bold</b> text</p>",
+                "environment": null
+            }
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(issue.description().as_deref(), Some("*bold* text"));
+    assert_eq!(
+        issue.rendered::<String>("description").unwrap().unwrap(),
+        "<p><b>bold</b> text</p>"
+    );
+    assert_eq!(
+        issue
+            .rendered::<Option<String>>("environment")
+            .unwrap()
+            .unwrap(),
+        None
+    );
+    assert!(issue.rendered::<String>("summary").is_none());
+
+    let plain: Issue = serde_json::from_str(
+        r#"{"self": "https://host/rest/api/2/issue/1", "id": "1", "key": "KEY-1", "fields": {}}"#,
+    )
+    .unwrap();
+    assert!(plain.rendered_fields.is_none());
+    assert!(plain.rendered::<String>("description").is_none());
+}
+
+#[test]
+fn attachment_created_at() {
+    let attachment = |created: &str| -> Attachment {
+        serde_json::from_value(serde_json::json!({
+            "id": "10000",
+            "self": "https://host/rest/api/2/attachment/10000",
+            "filename": "notes.txt",
+            "author": { "active": true, "displayName": "Ann", "name": "ann", "self": "https://host/rest/api/2/user?username=ann" },
+            "created": created,
+            "size": 5,
+            "mimeType": "text/plain",
+            "content": "https://host/secure/attachment/10000/notes.txt"
+        }))
+        .unwrap()
+    };
+
+    assert_eq!(
+        attachment("2024-01-01T09:00:00.000+0000").created_at(),
+        Some(datetime!(2024-01-01 09:00 UTC))
+    );
+    assert_eq!(
+        attachment("2024-01-01T09:00:00Z").created_at(),
+        Some(datetime!(2024-01-01 09:00 UTC))
+    );
+    let unparsable = attachment("yesterday");
+    assert_eq!(unparsable.created_at(), None);
+    assert_eq!(unparsable.created, "yesterday");
+}
+
+#[test]
+fn history_created_at() {
+    let history = |created: &str| -> History {
+        serde_json::from_value(serde_json::json!({
+            "author": { "active": true, "displayName": "Ann", "name": "ann", "self": "https://host/rest/api/2/user?username=ann" },
+            "created": created,
+            "items": []
+        }))
+        .unwrap()
+    };
+
+    assert_eq!(
+        history("2024-01-01T09:00:00.000+0100").created_at(),
+        Some(datetime!(2024-01-01 09:00 +01:00))
+    );
+    assert_eq!(
+        history("2024-01-01T09:00:00.000Z").created_at(),
+        Some(datetime!(2024-01-01 09:00 UTC))
+    );
+    assert_eq!(history("").created_at(), None);
+}
+
+#[test]
+fn issue_type_standard() {
+    let issue_type = |name: &str| -> IssueType {
+        serde_json::from_value(serde_json::json!({
+            "description": "",
+            "iconUrl": "https://host/icon.png",
+            "id": "1",
+            "name": name,
+            "self": "https://host/rest/api/2/issuetype/1",
+            "subtask": false
+        }))
+        .unwrap()
+    };
+
+    assert_eq!(issue_type("Bug").standard(), Some(StandardIssueType::Bug));
+    assert_eq!(issue_type("task").standard(), Some(StandardIssueType::Task));
+    assert_eq!(
+        issue_type("Story").standard(),
+        Some(StandardIssueType::Story)
+    );
+    assert_eq!(issue_type("EPIC").standard(), Some(StandardIssueType::Epic));
+    assert_eq!(
+        issue_type("Sub-task").standard(),
+        Some(StandardIssueType::Subtask)
+    );
+    assert_eq!(
+        issue_type("Subtask").standard(),
+        Some(StandardIssueType::Subtask)
+    );
+
+    let custom = issue_type("Incident");
+    assert_eq!(custom.standard(), None);
+    assert_eq!(custom.name, "Incident");
+}
+
+#[test]
+fn issue_related_issues() {
+    let link_type = r#"{"id": "1", "name": "Blocks", "inward": "is blocked by", "outward": "blocks", "self": "https://host/rest/api/2/issueLinkType/1"}"#;
+    let linked = |key: &str| {
+        format!(
+            r#"{{"self": "https://host/rest/api/2/issue/{key}", "id": "{key}", "key": "{key}", "fields": {{}}}}"#
+        )
+    };
+    let issue: Issue = serde_json::from_str(&format!(
+        r#"{{
+            "self": "https://host/rest/api/2/issue/1",
+            "id": "1",
+            "key": "KEY-1",
+            "fields": {{
+                "issuelinks": [
+                    {{"id": "10", "self": "https://host/rest/api/2/issueLink/10", "type": {link_type}, "outwardIssue": {}}},
+                    {{"id": "11", "self": "https://host/rest/api/2/issueLink/11", "type": {link_type}, "inwardIssue": {}}}
+                ]
+            }}
+        }}"#,
+        linked("KEY-2"),
+        linked("KEY-3")
+    ))
+    .unwrap();
+
+    assert_eq!(
+        issue.related_issues(),
+        vec![
+            RelatedIssue {
+                key: "KEY-2".to_owned(),
+                relation: "blocks".to_owned(),
+                link_type: "Blocks".to_owned(),
+                direction: LinkDirection::Outward,
+            },
+            RelatedIssue {
+                key: "KEY-3".to_owned(),
+                relation: "is blocked by".to_owned(),
+                link_type: "Blocks".to_owned(),
+                direction: LinkDirection::Inward,
+            },
+        ]
+    );
+
+    let unlinked: Issue = serde_json::from_str(
+        r#"{"self": "https://host/rest/api/2/issue/1", "id": "1", "key": "KEY-1", "fields": {}}"#,
+    )
+    .unwrap();
+    assert!(unlinked.related_issues().is_empty());
+}
+
+#[test]
+fn comment_datetime_formats() {
+    let comment: Comment = serde_json::from_str(
+        r#"{
+            "self": "https://host/rest/api/2/issue/10010/comment/10000",
+            "id": "10000",
+            "body": "Hello",
+            "created": "2024-01-01T10:00:00.000+0530",
+            "updated": "2024-01-02T10:00:00Z"
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(comment.created, Some(datetime!(2024-01-01 10:00 +05:30)));
+    assert_eq!(comment.updated, Some(datetime!(2024-01-02 10:00 UTC)));
+
+    // serializes back in the format Jira uses
+    let value = serde_json::to_value(&comment).unwrap();
+    assert_eq!(value["created"], "2024-01-01T10:00:00.000+0530");
+    assert_eq!(value["updated"], "2024-01-02T10:00:00.000+0000");
+
+    let without_dates: Comment = serde_json::from_str(
+        r#"{"self": "https://host/rest/api/2/issue/10010/comment/10000", "body": "Hello", "updated": null}"#,
+    )
+    .unwrap();
+    assert_eq!(without_dates.created, None);
+    assert_eq!(without_dates.updated, None);
+}
+
+#[test]
+fn issue_changelog_inline() {
+    let issue: Issue = serde_json::from_str(
+        r#"{
+            "self": "http://jira.com/rest/api/2/issue/1",
+            "id": "1",
+            "key": "TEST-1",
+            "fields": {},
+            "changelog": {
+                "startAt": 0,
+                "maxResults": 1,
+                "total": 1,
+                "histories": [{
+                    "author": {
+                        "self": "http://jira.com/rest/api/2/user?username=fred",
+                        "name": "fred",
+                        "displayName": "Fred",
+                        "active": true
+                    },
+                    "created": "2024-01-02T10:00:00.000+0000",
+                    "items": [{
+                        "field": "status",
+                        "from": "1",
+                        "fromString": "Open",
+                        "to": "3",
+                        "toString": "In Progress"
+                    }]
+                }]
+            }
+        }"#,
+    )
+    .unwrap();
+
+    let changelog = issue.changelog_inline().unwrap();
+    assert_eq!(changelog.histories.len(), 1);
+    assert_eq!(
+        changelog.histories[0].items[0].from_string.as_deref(),
+        Some("Open")
+    );
+
+    let mut bare = issue.clone();
+    bare.changelog = None;
+    assert!(bare.changelog_inline().is_none());
+}
+
+const ISSUE_WITH_UNKNOWN_KEYS: &str = r#"{
+    "self": "http://jira.com/rest/api/2/issue/1",
+    "id": "1",
+    "key": "TEST-1",
+    "expand": "renderedFields,names",
+    "brandNewThing": { "enabled": true },
+    "fields": {
+        "project": {
+            "id": "10000",
+            "key": "TEST",
+            "name": "Test",
+            "projectTypeKey": "software"
+        }
+    }
+}"#;
+
+#[test]
+fn unknown_keys_are_ignored() {
+    let issue: Issue = serde_json::from_str(ISSUE_WITH_UNKNOWN_KEYS).unwrap();
+
+    assert_eq!(issue.key, "TEST-1");
+    assert_eq!(issue.expand.as_deref(), Some("renderedFields,names"));
+    assert_eq!(issue.project().unwrap().key, "TEST");
+}
+
+#[cfg(feature = "extra-keys")]
+#[test]
+fn unknown_keys_are_kept_in_extra() {
+    let issue: Issue = serde_json::from_str(ISSUE_WITH_UNKNOWN_KEYS).unwrap();
+
+    assert_eq!(issue.extra.len(), 1);
+    assert_eq!(issue.extra["brandNewThing"]["enabled"], true);
+    assert!(!issue.extra.contains_key("expand"));
+    assert!(!issue.extra.contains_key("fields"));
+
+    let project = issue.project().unwrap();
+    assert_eq!(project.key, "TEST");
+    assert_eq!(project.extra["projectTypeKey"], "software");
+
+    // extra keys survive a round trip
+    let reparsed: Issue = serde_json::from_value(serde_json::to_value(&issue).unwrap()).unwrap();
+    assert_eq!(reparsed.extra, issue.extra);
+}
+
+#[test]
+fn issue_names_and_schema() {
+    let issue: Issue = serde_json::from_str(
+        r#"{
+            "self": "http://jira.com/rest/api/2/issue/1",
+            "id": "1",
+            "key": "TEST-1",
+            "fields": {
+                "summary": "Hello",
+                "customfield_10002": 5.0
+            },
+            "names": {
+                "summary": "Summary",
+                "customfield_10002": "Story Points"
+            },
+            "schema": {
+                "summary": { "type": "string", "system": "summary" },
+                "customfield_10002": {
+                    "type": "number",
+                    "custom": "com.atlassian.jira.plugin.system.customfieldtypes:float",
+                    "customId": 10002
+                }
+            }
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        issue.field_by_name::<f64>("Story Points").unwrap().unwrap(),
+        5.0
+    );
+    assert!(issue.field_by_name::<String>("Sprint").is_none());
+    let schema = issue.schema.as_ref().unwrap();
+    assert_eq!(schema["customfield_10002"].type_name, "number");
+    assert_eq!(schema["customfield_10002"].custom_id, Some(10002));
+    assert_eq!(schema["summary"].system.as_deref(), Some("summary"));
+    #[cfg(feature = "extra-keys")]
+    assert!(issue.extra.is_empty());
+
+    let mut plain = issue.clone();
+    plain.names = None;
+    assert!(plain.field_by_name::<f64>("Story Points").is_none());
+}
+
+#[test]
+fn issue_parent_and_epic_keys() {
+    let issue: Issue = serde_json::from_str(
+        r#"{
+            "self": "http://jira.com/rest/api/2/issue/2",
+            "id": "2",
+            "key": "TEST-2",
+            "fields": {
+                "parent": { "id": "1", "key": "TEST-1", "fields": { "summary": "Parent" } },
+                "customfield_10014": "TEST-100",
+                "customfield_10015": { "id": "100", "key": "TEST-101" },
+                "customfield_10016": null
+            }
+        }"#,
+    )
+    .unwrap();
+
+    // the parent lacks `self`, so it couldn't be read as a full issue
+    assert!(issue.parent().is_none());
+    assert_eq!(issue.parent_key().as_deref(), Some("TEST-1"));
+    assert_eq!(
+        issue.epic_key("customfield_10014").as_deref(),
+        Some("TEST-100")
+    );
+    assert_eq!(
+        issue.epic_key("customfield_10015").as_deref(),
+        Some("TEST-101")
+    );
+    assert_eq!(issue.epic_key("customfield_10016"), None);
+    assert_eq!(issue.epic_key("customfield_99999"), None);
+
+    let mut orphan = issue.clone();
+    orphan.fields.remove("parent");
+    assert_eq!(orphan.parent_key(), None);
+}
+
+#[test]
+fn search_results_by_key() {
+    let results: SearchResults = serde_json::from_str(
+        r#"{
+            "total": 3,
+            "maxResults": 50,
+            "startAt": 0,
+            "issues": [
+                {"self": "http://jira.com/rest/api/2/issue/1", "id": "1", "key": "TEST-1", "fields": {"summary": "One"}},
+                {"self": "http://jira.com/rest/api/2/issue/2", "id": "2", "key": "TEST-2", "fields": {"summary": "Two"}},
+                {"self": "http://jira.com/rest/api/2/issue/3", "id": "3", "key": "OTHER-1", "fields": {"summary": "Three"}}
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    let index = results.by_key();
+    assert_eq!(index.len(), 3);
+    assert_eq!(index["TEST-2"].id, "2");
+    assert_eq!(index["OTHER-1"].summary().as_deref(), Some("Three"));
+    assert!(!index.contains_key("TEST-3"));
+
+    let owned = results.into_by_key();
+    assert_eq!(owned.len(), 3);
+    assert_eq!(owned["TEST-1"].summary().as_deref(), Some("One"));
+}
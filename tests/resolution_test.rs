@@ -0,0 +1,76 @@
+extern crate gouqi;
+extern crate mockito;
+
+use gouqi::*;
+use mockito::Server;
+
+#[test]
+fn list_resolutions() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/rest/api/latest/resolution")
+        .with_body(
+            r#"[
+                {
+                    "self": "http://jira/rest/api/2/resolution/1",
+                    "id": "1",
+                    "name": "Fixed",
+                    "description": "A fix for this issue is checked into the tree and tested."
+                },
+                {
+                    "self": "http://jira/rest/api/2/resolution/2",
+                    "id": "2",
+                    "name": "Won't Fix",
+                    "description": "The problem described is an issue which will never be fixed."
+                }
+            ]"#,
+        )
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let resolutions = jira.resolutions().list().unwrap();
+
+    mock.assert();
+    assert_eq!(resolutions.len(), 2);
+    assert_eq!(resolutions[1].id.as_deref(), Some("2"));
+    assert_eq!(resolutions[1].name, "Won't Fix");
+}
+
+#[test]
+fn get_resolution() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/rest/api/latest/resolution/1")
+        .with_body(
+            r#"{
+                "self": "http://jira/rest/api/2/resolution/1",
+                "id": "1",
+                "name": "Fixed",
+                "description": "Fixed and tested."
+            }"#,
+        )
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let resolution = jira.resolutions().get("1").unwrap();
+
+    mock.assert();
+    assert_eq!(resolution.name, "Fixed");
+    assert_eq!(resolution.description.as_deref(), Some("Fixed and tested."));
+    assert_eq!(
+        resolution.self_link.as_deref(),
+        Some("http://jira/rest/api/2/resolution/1")
+    );
+}
+
+#[test]
+fn transition_resolution_only_sends_name() {
+    let options = TransitionTriggerOptions::builder("5")
+        .resolution("Fixed")
+        .build();
+
+    assert_eq!(
+        serde_json::to_value(&options.fields).unwrap(),
+        serde_json::json!({ "resolution": { "name": "Fixed" } })
+    );
+}
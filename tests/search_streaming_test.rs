@@ -0,0 +1,89 @@
+#![cfg(feature = "streaming")]
+
+extern crate gouqi;
+extern crate mockito;
+
+use gouqi::*;
+use mockito::Server;
+use std::time::Instant;
+
+fn large_page(count: u64) -> String {
+    let issues = (0..count)
+        .map(|n| {
+            format!(
+                r#"{{"self": "http://jira/rest/api/2/issue/{n}", "id": "{n}", "key": "TEST-{n}", "fields": {{"summary": "Issue {n}", "description": "{}"}}}}"#,
+                "x".repeat(512)
+            )
+        })
+        .collect::<Vec<_>>();
+    format!(
+        r#"{{"expand": "schema,names", "startAt": 0, "maxResults": {count}, "total": {}, "issues": [{}], "names": {{}}}}"#,
+        count * 2,
+        issues.join(",")
+    )
+}
+
+#[test]
+fn stream_large_page() {
+    let count = 5_000;
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/rest/api/latest/search")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "jql".into(),
+            "project = TEST".into(),
+        ))
+        .with_body(large_page(count))
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let started = Instant::now();
+    let mut keys = Vec::new();
+    let page = jira
+        .search()
+        .stream("project = TEST", &SearchOptions::default(), |issue| {
+            keys.push(issue.key)
+        })
+        .unwrap();
+    eprintln!("streamed {count} issues in {:?}", started.elapsed());
+
+    mock.assert();
+    assert_eq!(
+        page,
+        SearchPage {
+            total: count * 2,
+            max_results: count,
+            start_at: 0,
+            returned: count,
+        }
+    );
+    assert_eq!(keys.len() as u64, count);
+    assert_eq!(keys.first().map(String::as_str), Some("TEST-0"));
+    assert_eq!(keys.last(), Some(&format!("TEST-{}", count - 1)));
+}
+
+#[test]
+fn stream_maps_errors() {
+    let mut server = Server::new();
+    server
+        .mock("GET", "/rest/api/latest/search")
+        .match_query(mockito::Matcher::Any)
+        .with_status(400)
+        .with_body(r#"{"errorMessages": ["Error in the JQL Query"], "errors": {}}"#)
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let result = jira
+        .search()
+        .stream("project = ", &SearchOptions::default(), |_| {
+            panic!("no issues expected")
+        });
+
+    match result {
+        Err(Error::Fault { code, errors }) => {
+            assert_eq!(code.as_u16(), 400);
+            assert_eq!(errors.error_messages, vec!["Error in the JQL Query"]);
+        }
+        other => panic!("expected a fault, got {other:?}"),
+    }
+}
@@ -0,0 +1,597 @@
+extern crate gouqi;
+extern crate mockito;
+
+use gouqi::*;
+use mockito::{Matcher, Server};
+
+#[test]
+fn search_with_expand() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/rest/api/latest/search")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("jql".into(), "project = TEST".into()),
+            Matcher::UrlEncoded("expand".into(), "changelog,renderedFields".into()),
+        ]))
+        .with_body(
+            r#"{
+                "total": 1,
+                "maxResults": 50,
+                "startAt": 0,
+                "expand": "names,schema",
+                "issues": [{
+                    "self": "http://jira/rest/api/2/issue/10000",
+                    "id": "10000",
+                    "key": "TEST-1",
+                    "fields": {},
+                    "renderedFields": {},
+                    "changelog": {
+                        "startAt": 0,
+                        "maxResults": 1,
+                        "total": 1,
+                        "histories": [{
+                            "id": "1",
+                            "author": {
+                                "self": "http://jira/rest/api/2/user?username=fred",
+                                "displayName": "Fred",
+                                "active": true
+                            },
+                            "created": "2024-01-01T09:00:00.000+0000",
+                            "items": [{
+                                "field": "status",
+                                "from": "1",
+                                "fromString": "Open",
+                                "to": "3",
+                                "toString": "In Progress"
+                            }]
+                        }]
+                    }
+                }]
+            }"#,
+        )
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let results = jira
+        .search()
+        .list(
+            "project = TEST",
+            &SearchOptions::builder()
+                .expand(vec!["changelog", "renderedFields"])
+                .build(),
+        )
+        .unwrap();
+
+    mock.assert();
+    let changelog = results.issues[0].changelog.as_ref().unwrap();
+    assert_eq!(changelog.histories.len(), 1);
+    assert_eq!(
+        changelog.histories[0].items[0].to_string.as_deref(),
+        Some("In Progress")
+    );
+}
+
+fn issues_page(keys: std::ops::Range<u64>, total: u64, max_results: u64) -> String {
+    let issues = keys
+        .clone()
+        .map(|n| {
+            format!(
+                r#"{{"self": "http://jira/rest/api/2/issue/{n}", "id": "{n}", "key": "TEST-{n}", "fields": {{}}}}"#
+            )
+        })
+        .collect::<Vec<_>>();
+    format!(
+        r#"{{"total": {total}, "maxResults": {max_results}, "startAt": {}, "issues": [{}]}}"#,
+        keys.start,
+        issues.join(",")
+    )
+}
+
+fn page_mock(server: &mut Server, start_at: u64, body: String) -> mockito::Mock {
+    server
+        .mock("GET", "/rest/api/latest/search")
+        .match_query(Matcher::UrlEncoded("startAt".into(), start_at.to_string()))
+        .with_body(body)
+        .create()
+}
+
+fn iterate(server: &Server) -> Vec<String> {
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let options = SearchOptions::builder().start_at(0).max_results(2).build();
+    jira.search()
+        .iter("project = TEST", &options)
+        .unwrap()
+        .map(|issue| issue.key)
+        .collect()
+}
+
+#[test]
+fn iter_exactly_one_page() {
+    let mut server = Server::new();
+    let first = page_mock(&mut server, 0, issues_page(0..2, 2, 2));
+    let extra = page_mock(&mut server, 2, issues_page(2..2, 2, 2)).expect(0);
+
+    assert_eq!(iterate(&server), vec!["TEST-0", "TEST-1"]);
+    first.assert();
+    extra.assert();
+}
+
+#[test]
+fn iter_exactly_two_pages() {
+    let mut server = Server::new();
+    let first = page_mock(&mut server, 0, issues_page(0..2, 4, 2));
+    let second = page_mock(&mut server, 2, issues_page(2..4, 4, 2));
+    let extra = page_mock(&mut server, 4, issues_page(4..4, 4, 2)).expect(0);
+
+    assert_eq!(
+        iterate(&server),
+        vec!["TEST-0", "TEST-1", "TEST-2", "TEST-3"]
+    );
+    first.assert();
+    second.assert();
+    extra.assert();
+}
+
+#[test]
+fn iter_empty_results() {
+    let mut server = Server::new();
+    let first = page_mock(&mut server, 0, issues_page(0..0, 0, 2));
+    let extra = page_mock(&mut server, 2, issues_page(2..2, 0, 2)).expect(0);
+
+    assert!(iterate(&server).is_empty());
+    first.assert();
+    extra.assert();
+}
+
+#[test]
+fn list_checked_rejects_malformed_jql_without_sending() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/rest/api/latest/search")
+        .match_query(Matcher::Any)
+        .expect(0)
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let result = jira
+        .search()
+        .list_checked(r#"summary ~ "oops"#, &SearchOptions::default());
+
+    assert!(matches!(result, Err(Error::InvalidInput(_))));
+    mock.assert();
+}
+
+#[test]
+fn list_checked_sends_valid_jql() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/rest/api/latest/search")
+        .match_query(Matcher::UrlEncoded("jql".into(), "project = TEST".into()))
+        .with_body(issues_page(0..1, 1, 50))
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let results = jira
+        .search()
+        .list_checked("project = TEST", &SearchOptions::default())
+        .unwrap();
+
+    assert_eq!(results.issues.len(), 1);
+    mock.assert();
+}
+
+#[test]
+fn iter_follows_clamped_max_results() {
+    let mut server = Server::new();
+    let first = server
+        .mock("GET", "/rest/api/latest/search")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("startAt".into(), "0".into()),
+            Matcher::UrlEncoded("maxResults".into(), "1000".into()),
+        ]))
+        .with_body(issues_page(0..2, 5, 2))
+        .create();
+    let second = server
+        .mock("GET", "/rest/api/latest/search")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("startAt".into(), "2".into()),
+            Matcher::UrlEncoded("maxResults".into(), "2".into()),
+        ]))
+        .with_body(issues_page(2..4, 5, 2))
+        .create();
+    let third = server
+        .mock("GET", "/rest/api/latest/search")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("startAt".into(), "4".into()),
+            Matcher::UrlEncoded("maxResults".into(), "2".into()),
+        ]))
+        .with_body(issues_page(4..5, 5, 2))
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let options = SearchOptions::builder()
+        .start_at(0)
+        .max_results(1000)
+        .build();
+    let keys = jira
+        .search()
+        .iter("project = TEST", &options)
+        .unwrap()
+        .map(|issue| issue.key)
+        .collect::<Vec<_>>();
+
+    assert_eq!(keys, vec!["TEST-0", "TEST-1", "TEST-2", "TEST-3", "TEST-4"]);
+    first.assert();
+    second.assert();
+    third.assert();
+}
+
+#[test]
+fn search_with_properties() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/rest/api/latest/search")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded(
+                "jql".into(),
+                "issue.property[app.meta].synced = true".into(),
+            ),
+            Matcher::UrlEncoded("properties".into(), "app.meta".into()),
+        ]))
+        .with_body(
+            r#"{
+                "total": 1,
+                "maxResults": 50,
+                "startAt": 0,
+                "issues": [{
+                    "self": "http://jira/rest/api/2/issue/1",
+                    "id": "1",
+                    "key": "TEST-1",
+                    "fields": {},
+                    "properties": { "app.meta": { "synced": true, "revision": 3 } }
+                }]
+            }"#,
+        )
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let results = jira
+        .search()
+        .list(
+            "issue.property[app.meta].synced = true",
+            &SearchOptions::builder()
+                .properties(vec!["app.meta"])
+                .build(),
+        )
+        .unwrap();
+
+    mock.assert();
+    let issue = &results.issues[0];
+    let meta = issue
+        .property::<serde_json::Value>("app.meta")
+        .unwrap()
+        .unwrap();
+    assert_eq!(meta["revision"], 3);
+    assert!(issue.property::<serde_json::Value>("app.other").is_none());
+}
+
+#[test]
+fn jql_autocomplete_data() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/rest/api/latest/jql/autocompletedata")
+        .with_body(
+            r#"{
+                "visibleFieldNames": [
+                    {
+                        "value": "status",
+                        "displayName": "Status",
+                        "orderable": "true",
+                        "searchable": "true",
+                        "auto": "true",
+                        "operators": ["=", "!=", "in", "not in"],
+                        "types": ["com.atlassian.jira.issue.status.Status"]
+                    },
+                    {
+                        "value": "cf[10010]",
+                        "displayName": "Severity - cf[10010]",
+                        "cfid": "cf[10010]",
+                        "operators": ["="],
+                        "types": ["com.atlassian.jira.issue.customfields.option.Option"]
+                    }
+                ],
+                "visibleFunctionNames": [
+                    {
+                        "value": "currentUser()",
+                        "displayName": "currentUser()",
+                        "types": ["com.atlassian.crowd.embedded.api.User"]
+                    }
+                ],
+                "jqlReservedWords": ["and", "or", "order"]
+            }"#,
+        )
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let data = jira.search().autocomplete_data().unwrap();
+
+    mock.assert();
+    assert_eq!(data.visible_field_names.len(), 2);
+    assert_eq!(data.visible_field_names[0].operators[2], "in");
+    assert_eq!(
+        data.visible_field_names[1].cfid.as_deref(),
+        Some("cf[10010]")
+    );
+    assert_eq!(data.visible_function_names[0].value, "currentUser()");
+    assert_eq!(data.jql_reserved_words, vec!["and", "or", "order"]);
+}
+
+#[test]
+fn jql_autocomplete_suggestions() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/rest/api/latest/jql/autocompletedata/suggestions")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("fieldName".into(), "status".into()),
+            Matcher::UrlEncoded("fieldValue".into(), "In Pr".into()),
+        ]))
+        .with_body(
+            r#"{"results": [{"value": "In Progress", "displayName": "<b>In Pr</b>ogress"}]}"#,
+        )
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let suggestions = jira
+        .search()
+        .autocomplete_suggestions("status", "In Pr")
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(suggestions.results.len(), 1);
+    assert_eq!(suggestions.results[0].value, "In Progress");
+}
+
+#[test]
+fn issue_picker() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/rest/api/latest/issue/picker")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("query".into(), "login".into()),
+            Matcher::UrlEncoded("currentJQL".into(), "project = TEST".into()),
+        ]))
+        .with_body(
+            r#"{
+                "sections": [
+                    {
+                        "label": "History Search",
+                        "sub": "Showing 1 of 1 matching issues",
+                        "id": "hs",
+                        "issues": [{
+                            "key": "TEST-7",
+                            "keyHtml": "TEST-7",
+                            "img": "/images/icons/issuetypes/bug.png",
+                            "summary": "Fix <b>login</b> page",
+                            "summaryText": "Fix login page"
+                        }]
+                    },
+                    {
+                        "label": "Current Search",
+                        "id": "cs",
+                        "issues": []
+                    }
+                ]
+            }"#,
+        )
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let sections = jira
+        .search()
+        .picker("login", Some("project = TEST".to_owned()))
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(sections.len(), 2);
+    assert_eq!(sections[0].id, "hs");
+    assert_eq!(sections[0].issues[0].key, "TEST-7");
+    assert_eq!(sections[0].issues[0].summary, "Fix login page");
+    assert!(sections[1].issues.is_empty());
+}
+
+#[test]
+fn iter_with_overridden_options() {
+    let mut server = Server::new();
+    let second = page_mock(&mut server, 2, issues_page(2..4, 6, 2));
+    let third = page_mock(&mut server, 4, issues_page(4..6, 6, 2));
+    let first = page_mock(&mut server, 0, issues_page(0..2, 6, 2)).expect(0);
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let defaults = SearchOptions::builder().fields(vec!["summary"]).build();
+    let options = defaults.clone().with_start_at(2).with_max_results(2);
+    let keys = jira
+        .search()
+        .iter("project = TEST", &options)
+        .unwrap()
+        .map(|issue| issue.key)
+        .collect::<Vec<_>>();
+
+    assert_eq!(keys, vec!["TEST-2", "TEST-3", "TEST-4", "TEST-5"]);
+    first.assert();
+    second.assert();
+    third.assert();
+}
+
+#[test]
+fn search_results_remember_the_requested_fields() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/rest/api/latest/search")
+        .match_query(Matcher::UrlEncoded(
+            "fields".into(),
+            "summary,-comment".into(),
+        ))
+        .with_body(issues_page(0..1, 1, 50))
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let options = SearchOptions::builder()
+        .fields(vec!["summary", "-comment"])
+        .build();
+    let results = jira.search().list("project = TEST", &options).unwrap();
+
+    mock.assert();
+    let issue = &results.issues[0];
+    assert!(issue.was_requested("summary"));
+    assert!(!issue.was_requested("status"));
+    assert!(!issue.was_requested("comment"));
+}
+
+#[test]
+fn search_results_without_fields_report_navigable_fields_requested() {
+    let mut server = Server::new();
+    let _mock = page_mock(&mut server, 0, issues_page(0..1, 1, 50));
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let options = SearchOptions::builder().start_at(0).build();
+    let results = jira.search().list("project = TEST", &options).unwrap();
+
+    assert!(results.issues[0].was_requested("status"));
+    assert!(!results.issues[0].was_requested("comment"));
+}
+
+#[test]
+fn search_with_expanded_operations() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/rest/api/latest/search")
+        .match_query(Matcher::UrlEncoded("expand".into(), "operations".into()))
+        .with_body(
+            r#"{
+                "total": 1,
+                "maxResults": 50,
+                "startAt": 0,
+                "issues": [{
+                    "self": "http://jira/rest/api/2/issue/10000",
+                    "id": "10000",
+                    "key": "TEST-1",
+                    "fields": {},
+                    "operations": {
+                        "linkGroups": [{
+                            "id": "view.issue.opsbar",
+                            "links": [],
+                            "groups": [{
+                                "id": "edit-issue_container",
+                                "weight": 1,
+                                "links": [{
+                                    "id": "edit-issue",
+                                    "styleClass": "issueaction-edit-issue",
+                                    "iconClass": "aui-icon aui-icon-small aui-iconfont-edit",
+                                    "label": "Edit",
+                                    "title": "Edit this issue",
+                                    "href": "/secure/EditIssue!default.jspa?id=10000",
+                                    "weight": 1
+                                }],
+                                "groups": []
+                            }]
+                        }, {
+                            "id": "jira.issue.tools",
+                            "header": {"id": "jira.issue.tools", "label": "More"},
+                            "links": [{"id": "clone-issue", "label": "Clone", "href": "/secure/CloneIssueDetails!default.jspa?id=10000"}],
+                            "groups": []
+                        }]
+                    }
+                }]
+            }"#,
+        )
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let options = SearchOptions::builder().expand(vec!["operations"]).build();
+    let results = jira.search().list("project = TEST", &options).unwrap();
+
+    mock.assert();
+    let issue = &results.issues[0];
+    #[cfg(feature = "extra-keys")]
+    assert!(issue.extra.is_empty());
+    let operations = issue.operations().unwrap();
+    assert_eq!(operations.link_groups.len(), 2);
+    let header = operations.link_groups[1].header.as_ref().unwrap();
+    assert_eq!(header.label.as_deref(), Some("More"));
+    let labels = operations
+        .links()
+        .into_iter()
+        .map(|link| link.label.as_deref().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(labels, vec!["Edit", "Clone"]);
+}
+
+#[test]
+fn issues_without_expanded_operations_have_none() {
+    let mut server = Server::new();
+    let _mock = page_mock(&mut server, 0, issues_page(0..1, 1, 50));
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let options = SearchOptions::builder().start_at(0).build();
+    let results = jira.search().list("project = TEST", &options).unwrap();
+
+    assert!(results.issues[0].operations().is_none());
+}
+
+fn count_only_mock(server: &mut Server, jql: &str, total: u64) -> mockito::Mock {
+    server
+        .mock("GET", "/rest/api/latest/search")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("jql".into(), jql.into()),
+            Matcher::UrlEncoded("maxResults".into(), "0".into()),
+        ]))
+        .with_body(format!(
+            r#"{{"total": {total}, "maxResults": 0, "startAt": 0, "issues": []}}"#
+        ))
+        .create()
+}
+
+#[test]
+fn list_with_zero_max_results_only_counts() {
+    let mut server = Server::new();
+    let mock = count_only_mock(&mut server, "project = TEST", 42);
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let options = SearchOptions::builder().max_results(0).build();
+    let results = jira.search().list("project = TEST", &options).unwrap();
+
+    mock.assert();
+    assert!(results.issues.is_empty());
+    assert_eq!(results.total, 42);
+}
+
+#[test]
+fn count_and_exists() {
+    let mut server = Server::new();
+    let some = count_only_mock(&mut server, "project = TEST", 42).expect(2);
+    let none = count_only_mock(&mut server, "project = EMPTY", 0);
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    assert_eq!(jira.search().count("project = TEST").unwrap(), 42);
+    assert!(jira.search().exists("project = TEST").unwrap());
+    assert!(!jira.search().exists("project = EMPTY").unwrap());
+
+    some.assert();
+    none.assert();
+}
+
+#[test]
+fn list_sends_the_query_argument_only_once() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/rest/api/latest/search")
+        .match_query(Matcher::Exact("jql=project+%3D+TEST".into()))
+        .with_body(issues_page(0..0, 0, 50))
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let options = SearchOptions::default().with_jql("project = OTHER");
+    jira.search().list("project = TEST", &options).unwrap();
+
+    mock.assert();
+}
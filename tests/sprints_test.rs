@@ -85,3 +85,70 @@ fn deserialise_sprint_results() {
     assert!(sprint_results.is_last);
     assert_eq!(sprint_results.values.len(), 1);
 }
+
+fn sprint_issues_page(start_at: u64, keys: &[&str]) -> String {
+    let issues = keys
+        .iter()
+        .map(|key| {
+            format!(r#"{{"self": "http://jira/rest/api/2/issue/{key}", "id": "1", "key": "{key}", "fields": {{}}}}"#)
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    format!(r#"{{"total": 3, "maxResults": 2, "startAt": {start_at}, "issues": [{issues}]}}"#)
+}
+
+#[test]
+fn list_sprint_issues() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/rest/agile/latest/sprint/7/issue")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "jql".into(),
+            "status = Done".into(),
+        ))
+        .with_body(sprint_issues_page(0, &["TEST-1"]))
+        .create();
+
+    let jira = gouqi::Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+    let results = jira
+        .sprints()
+        .issues(
+            7,
+            &gouqi::SearchOptions::builder().jql("status = Done").build(),
+        )
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(results.issues[0].key, "TEST-1");
+}
+
+#[test]
+fn iterate_sprint_issues() {
+    let mut server = mockito::Server::new();
+    let first = server
+        .mock("GET", "/rest/agile/latest/sprint/7/issue")
+        .match_query(mockito::Matcher::Missing)
+        .with_body(sprint_issues_page(0, &["TEST-1", "TEST-2"]))
+        .create();
+    let second = server
+        .mock("GET", "/rest/agile/latest/sprint/7/issue")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("startAt".into(), "2".into()),
+            mockito::Matcher::UrlEncoded("maxResults".into(), "2".into()),
+        ]))
+        .with_body(sprint_issues_page(2, &["TEST-3"]))
+        .create();
+
+    let jira = gouqi::Jira::new(server.url(), gouqi::Credentials::Anonymous).unwrap();
+    let options = gouqi::SearchOptions::default();
+    let keys = jira
+        .sprints()
+        .issues_iter(7, &options)
+        .unwrap()
+        .map(|issue| issue.key)
+        .collect::<Vec<_>>();
+
+    first.assert();
+    second.assert();
+    assert_eq!(keys, vec!["TEST-1", "TEST-2", "TEST-3"]);
+}
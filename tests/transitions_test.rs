@@ -0,0 +1,126 @@
+extern crate gouqi;
+
+use gouqi::{Credentials, Error, Jira, TransitionTriggerOptions};
+use mockito::{Matcher, Server, ServerGuard};
+use serde_json::json;
+
+const TRANSITIONS: &str = r#"{
+    "transitions": [
+        {
+            "id": "11",
+            "name": "Start Progress",
+            "to": { "id": "3", "name": "In Progress" },
+            "fields": {}
+        },
+        {
+            "id": "31",
+            "name": "Resolve",
+            "to": { "id": "5", "name": "Resolved" },
+            "fields": {
+                "resolution": {
+                    "required": true,
+                    "name": "Resolution",
+                    "hasDefaultValue": false,
+                    "allowedValues": [{ "id": "1", "name": "Fixed" }, { "id": "2", "name": "Won't Fix" }]
+                }
+            }
+        }
+    ]
+}"#;
+
+fn list_transitions(server: &mut ServerGuard) -> mockito::Mock {
+    server
+        .mock("GET", "/rest/api/latest/issue/TEST-1/transitions")
+        .match_query(Matcher::UrlEncoded(
+            "expand".into(),
+            "transitions.fields".into(),
+        ))
+        .with_body(TRANSITIONS)
+        .create()
+}
+
+fn rejected(result: gouqi::Result<()>) -> String {
+    match result {
+        Err(Error::InvalidInput(message)) => message,
+        other => panic!("expected invalid input, got {other:?}"),
+    }
+}
+
+#[test]
+fn trigger_validated_rejects_resolution_on_a_screen_without_it() {
+    let mut server = Server::new();
+    let list = list_transitions(&mut server);
+    let trigger = server
+        .mock("POST", "/rest/api/latest/issue/TEST-1/transitions")
+        .expect(0)
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let message = rejected(
+        jira.transitions("TEST-1").trigger_validated(
+            TransitionTriggerOptions::builder("11")
+                .resolution("Fixed")
+                .build(),
+        ),
+    );
+
+    list.assert();
+    trigger.assert();
+    assert_eq!(
+        message,
+        "transition rejected: field resolution is not on the screen of transition Start Progress"
+    );
+}
+
+#[test]
+fn trigger_validated_checks_required_and_allowed_values() {
+    let mut server = Server::new();
+    list_transitions(&mut server).expect(3);
+    let trigger = server
+        .mock("POST", "/rest/api/latest/issue/TEST-1/transitions")
+        .match_body(Matcher::Json(json!({
+            "transition": { "id": "31" },
+            "fields": { "resolution": { "name": "Fixed" } }
+        })))
+        .with_status(204)
+        .create();
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    let transitions = jira.transitions("TEST-1");
+
+    let missing = rejected(transitions.trigger_validated(TransitionTriggerOptions::new("31")));
+    assert!(missing.contains("missing required field Resolution (resolution)"));
+
+    let invalid = rejected(
+        transitions.trigger_validated(
+            TransitionTriggerOptions::builder("31")
+                .resolution("Done")
+                .build(),
+        ),
+    );
+    assert!(invalid.contains("is not allowed for field Resolution"));
+
+    transitions
+        .trigger_validated(
+            TransitionTriggerOptions::builder("31")
+                .resolution("Fixed")
+                .build(),
+        )
+        .unwrap();
+    trigger.assert();
+}
+
+#[test]
+fn trigger_validated_rejects_unavailable_transitions() {
+    let mut server = Server::new();
+    list_transitions(&mut server);
+
+    let jira = Jira::new(server.url(), Credentials::Anonymous).unwrap();
+    assert_eq!(
+        rejected(
+            jira.transitions("TEST-1")
+                .trigger_validated(TransitionTriggerOptions::new("99"))
+        ),
+        "transition 99 is not available for TEST-1"
+    );
+}
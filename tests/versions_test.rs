@@ -0,0 +1,75 @@
+extern crate gouqi;
+
+use gouqi::{Credentials, Jira, VersionCreationBody, VersionUpdateBody};
+use mockito::{Matcher, Server};
+use serde_json::json;
+
+fn version(id: &str, name: &str, released: bool, url: &str) -> String {
+    json!({
+        "self": format!("{url}/rest/api/2/version/{id}"),
+        "id": id,
+        "name": name,
+        "projectId": 10000,
+        "archived": false,
+        "released": released
+    })
+    .to_string()
+}
+
+#[test]
+fn create_release_and_delete_version() {
+    let mut server = Server::new();
+    let url = server.url();
+    let create = server
+        .mock("POST", "/rest/api/latest/version")
+        .match_body(Matcher::Json(json!({ "name": "2.0", "projectId": 10000 })))
+        .with_status(201)
+        .with_body(version("2", "2.0", false, &url))
+        .create();
+    let get = server
+        .mock("GET", "/rest/api/latest/version/1")
+        .with_body(version("1", "1.0", false, &url))
+        .create();
+    let release = server
+        .mock("PUT", "/rest/api/latest/version/1")
+        .match_body(Matcher::Json(json!({
+            "released": true,
+            "archived": false,
+            "moveUnfixedIssuesTo": format!("{url}/rest/api/2/version/2")
+        })))
+        .with_body(version("1", "1.0", true, &url))
+        .create();
+    let delete = server
+        .mock("DELETE", "/rest/api/latest/version/1")
+        .with_status(204)
+        .create();
+
+    let jira = Jira::new(url, Credentials::Anonymous).unwrap();
+    let versions = jira.versions();
+    let next = versions
+        .create_from_body(VersionCreationBody {
+            name: "2.0".to_owned(),
+            project_id: 10000,
+        })
+        .unwrap();
+    let current = versions.get("1").unwrap();
+    assert!(!current.released);
+
+    let released = versions
+        .update(
+            current.id.as_str(),
+            VersionUpdateBody {
+                released: true,
+                archived: false,
+                move_unfixed_issues_to: Some(next.self_link.clone()),
+            },
+        )
+        .unwrap();
+    assert!(released.released);
+    versions.delete(released.id).unwrap();
+
+    create.assert();
+    get.assert();
+    release.assert();
+    delete.assert();
+}